@@ -0,0 +1,125 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Neg, Range, Rem, Sub};
+
+use super::BoundsError;
+use crate::clamped::Clamped;
+use crate::float::FloatBounds;
+use crate::macros::*;
+
+macro_rules! impl_all {
+    ($type:ident, $inner:ty) => {
+        impl<B: FloatBounds<$inner>> $type<B> {
+            pub fn new(inner: $inner) -> Result<Self, BoundsError> {
+                Self::try_from(inner)
+            }
+            pub fn range(&self) -> Range<$inner> { B::MIN..B::MAX }
+        }
+
+        impl<B: FloatBounds<$inner>> TryFrom<$inner> for $type<B> {
+            type Error = BoundsError;
+
+            /// NaN is treated as being too small.
+            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
+                assert!(B::MIN < B::MAX, "MIN must be less than MAX");
+
+                if inner >= B::MAX {
+                    Err(BoundsError::TooLarge)
+                } else if inner.is_nan() || inner < B::MIN {
+                    Err(BoundsError::TooSmall)
+                } else {
+                    Ok(Self(inner, PhantomData))
+                }
+            }
+        }
+
+        impl<B: FloatBounds<$inner>> Neg for $type<B> {
+            type Output = Result<Self, BoundsError>;
+            fn neg(self) -> Self::Output { Self::try_from(-self.0) }
+        }
+
+        impl_float!($type, $inner);
+        impl_clamped_float!(
+            $type,
+            $inner,
+            Result<Self, BoundsError>,
+            Self::try_from
+        );
+
+        #[cfg(feature = "serde")]
+        impl_serde_float!($type, $inner, |inner| Self::try_from(inner)
+            .map_err(serde::de::Error::custom));
+    };
+}
+
+pub struct BoundedF32<B>(f32, PhantomData<B>);
+impl_all!(BoundedF32, f32);
+
+pub struct BoundedF64<B>(f64, PhantomData<B>);
+impl_all!(BoundedF64, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Unit;
+    impl FloatBounds<f32> for Unit {
+        const MIN: f32 = 0.0;
+        const MAX: f32 = 1.0;
+    }
+    impl FloatBounds<f64> for Unit {
+        const MIN: f64 = 0.0;
+        const MAX: f64 = 1.0;
+    }
+
+    struct Invalid;
+    impl FloatBounds<f32> for Invalid {
+        const MIN: f32 = f32::NAN;
+        const MAX: f32 = 1.0;
+    }
+
+    #[test]
+    fn can_create() {
+        let foo = BoundedF32::<Unit>::try_from(0.5).unwrap();
+        assert_eq!(foo.inner(), 0.5);
+        assert_eq!(foo.range(), 0.0..1.0);
+        assert_eq!(foo + 1.0, 1.5);
+    }
+
+    #[test]
+    fn cannot_create_outside_of_bounds() {
+        use BoundsError::*;
+        assert_eq!(BoundedF32::<Unit>::try_from(0.0).unwrap(), 0.0);
+        assert_eq!(BoundedF32::<Unit>::try_from(1.0), Err(TooLarge));
+        assert_eq!(BoundedF32::<Unit>::try_from(-0.1), Err(TooSmall));
+        assert_eq!(BoundedF64::<Unit>::try_from(f64::INFINITY), Err(TooLarge));
+        assert_eq!(BoundedF64::<Unit>::new(0.5).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn nan_is_too_small() {
+        let foo = BoundedF64::<Unit>::try_from(f64::NAN);
+        assert_eq!(foo, Err(BoundsError::TooSmall));
+    }
+
+    #[test]
+    fn test_neg() {
+        let foo = BoundedF64::<Unit>::try_from(0.5).unwrap();
+        assert_eq!(-foo, Err(BoundsError::TooSmall));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let foo: BoundedF64<Unit> = serde_json::from_str("0.5").unwrap();
+        assert_eq!(foo, 0.5);
+        assert!(serde_json::from_str::<BoundedF64<Unit>>("1.0").is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_use_invalid_bounds() {
+        let _ = BoundedF32::<Invalid>::try_from(0.5);
+    }
+}
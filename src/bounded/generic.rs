@@ -1,34 +1,122 @@
-use std::fmt;
-use std::ops::{Add, Div, Mul, Range, Rem, Sub};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Div, Mul, Neg, Range, Rem, Sub};
 
 use super::BoundsError;
+use crate::bounds::{Bounds, Integer};
+use crate::builder::Builder;
+use crate::clamped::Clamped;
 
 pub struct Bounded<T> {
-    inner: T,
-    min:   T,
-    max:   T,
+    inner:  T,
+    bounds: Bounds<T>,
 }
 
 impl<T: PartialOrd> Bounded<T> {
+    /// Panics if `min` is not less than `max`. See [`Bounded::try_new`] for a
+    /// version that returns [`BoundsError::InvalidBounds`] instead.
     pub fn new(inner: T, min: T, max: T) -> Result<Self, BoundsError> {
-        if min >= max {
-            panic!("MIN value must be less than MAX")
+        match Self::try_new(inner, min, max) {
+            Err(BoundsError::InvalidBounds) => {
+                panic!("MIN value must be less than MAX")
+            },
+            result => result,
         }
+    }
+
+    pub fn try_new(inner: T, min: T, max: T) -> Result<Self, BoundsError> {
+        Self::with_bounds(inner, Bounds::try_new(min, max)?)
+    }
 
-        if inner >= max {
+    /// Takes the bounds from `min..max`. An empty range is rejected with
+    /// [`BoundsError::InvalidBounds`].
+    pub fn from_range(inner: T, range: Range<T>) -> Result<Self, BoundsError> {
+        Self::try_new(inner, range.start, range.end)
+    }
+
+    /// Unlike the other generic types, this can still fail with
+    /// [`BoundsError::TooSmall`] or [`BoundsError::TooLarge`].
+    pub fn with_bounds(
+        inner: T, bounds: Bounds<T>,
+    ) -> Result<Self, BoundsError> {
+        if inner >= bounds.max {
             Err(BoundsError::TooLarge)
-        } else if inner < min {
+        } else if inner < bounds.min {
             Err(BoundsError::TooSmall)
         } else {
-            Ok(Self { inner, max, min })
+            Ok(Self { inner, bounds })
         }
     }
 
+    /// Returns a [`Builder`], which names each argument explicitly.
+    pub fn builder() -> Builder<T, Self> { Builder::new(Self::with_bounds) }
+
     pub fn inner(&self) -> &T { &self.inner }
     pub fn into_inner(self) -> T { self.inner }
-    pub fn range(&self) -> Range<&T> { &self.min..&self.max }
-    pub fn min_bound(&self) -> &T { &self.min }
-    pub fn max_bound(&self) -> &T { &self.max }
+    pub fn bounds(&self) -> &Bounds<T> { &self.bounds }
+    pub fn contains(&self, value: &T) -> bool { self.bounds.contains(value) }
+    pub fn range(&self) -> Range<&T> { self.bounds.range() }
+    pub fn min_bound(&self) -> &T { &self.bounds.min }
+    pub fn max_bound(&self) -> &T { &self.bounds.max }
+}
+
+impl<T: PartialOrd> Bounded<T> {
+    /// Returns whichever value is larger, or `other` if they're equal.
+    pub fn max_with(self, other: Self) -> Self {
+        if self.inner > other.inner {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns whichever value is smaller, or `self` if they're equal.
+    pub fn min_with(self, other: Self) -> Self {
+        if other.inner < self.inner {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone> Clamped for Bounded<T> {
+    type Inner = T;
+    type Output = Result<Self, BoundsError>;
+
+    fn inner(&self) -> T { self.inner.clone() }
+    fn min_bound(&self) -> T { self.bounds.min.clone() }
+    fn max_bound(&self) -> T { self.bounds.max.clone() }
+    fn clamp(&self, value: T) -> Result<Self, BoundsError> {
+        Bounded::with_bounds(value, self.bounds.clone())
+    }
+}
+
+/// Values out of range are saturated into `min..max`. As `max` is exclusive,
+/// values at or above it become `max - 1`, so this is only implemented for
+/// integers.
+impl<T: PartialOrd + Clone + Sub<Output = T> + Integer> crate::Clamp<T>
+    for Bounded<T>
+{
+    fn clamp(&self, value: T) -> T {
+        if value < self.bounds.min {
+            self.bounds.min.clone()
+        } else if value >= self.bounds.max {
+            self.bounds.max.clone() - T::ONE
+        } else {
+            value
+        }
+    }
+}
+
+/// Converts from `(inner, min, max)`. Like [`Bounded::try_new`], invalid bounds
+/// are returned as [`BoundsError::InvalidBounds`] rather than panicking.
+impl<T: PartialOrd> TryFrom<(T, T, T)> for Bounded<T> {
+    type Error = BoundsError;
+    fn try_from((inner, min, max): (T, T, T)) -> Result<Self, Self::Error> {
+        Self::try_new(inner, min, max)
+    }
 }
 
 //arithmetic
@@ -54,6 +142,14 @@ impl_arith!(Mul, mul, |this, other| this * other);
 impl_arith!(Div, div, |this, other| this / other);
 impl_arith!(Rem, rem, |this, other| this % other);
 
+// negation
+impl<T: PartialOrd + Neg<Output = T>> Neg for Bounded<T> {
+    type Output = Result<Self, BoundsError>;
+    fn neg(self) -> Self::Output {
+        Bounded::with_bounds(-self.inner, self.bounds)
+    }
+}
+
 // equality
 impl<T: PartialEq> PartialEq<T> for Bounded<T> {
     fn eq(&self, other: &T) -> bool { self.inner == *other }
@@ -63,13 +159,37 @@ impl<T: PartialEq> PartialEq<Bounded<T>> for Bounded<T> {
 }
 impl<T: Eq> Eq for Bounded<T> {}
 
+// ordering, which (like equality) only considers the inner value
+impl<T: PartialOrd> PartialOrd<T> for Bounded<T> {
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.inner.partial_cmp(other)
+    }
+}
+impl<T: PartialOrd> PartialOrd<Bounded<T>> for Bounded<T> {
+    fn partial_cmp(&self, other: &Bounded<T>) -> Option<Ordering> {
+        self.inner.partial_cmp(&other.inner)
+    }
+}
+
+// hashing has to agree with equality, so the bounds are ignored
+impl<T: Hash> Hash for Bounded<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.inner.hash(state) }
+}
+
+// Display only shows the inner value
+impl<T: fmt::Display> fmt::Display for Bounded<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
 // We can only implement Debug if T implements Debug
 impl<T: fmt::Debug> fmt::Debug for Bounded<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "Bounded {{inner: {:?}, min: {:?}, max: {:?}}}",
-            self.inner, self.min, self.max
+            self.inner, self.bounds.min, self.bounds.max
         )
     }
 }
@@ -78,18 +198,38 @@ impl<T: fmt::Debug> fmt::Debug for Bounded<T> {
 impl<T: Clone> Clone for Bounded<T> {
     fn clone(&self) -> Self {
         Self {
-            inner: self.inner.clone(),
-            min:   self.min.clone(),
-            max:   self.max.clone(),
+            inner:  self.inner.clone(),
+            bounds: self.bounds.clone(),
         }
     }
 }
 impl<T: Copy> Copy for Bounded<T> {}
 
+#[cfg(feature = "serde")]
+crate::macros::impl_serde_generic!(
+    Bounded,
+    [PartialOrd],
+    |inner, min, max| Bounded::try_new(inner, min, max)
+        .map_err(serde::de::Error::custom)
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn works_without_arithmetic() {
+        let letter = Bounded::new('q', 'a', 'z').unwrap();
+        assert_eq!(letter, 'q');
+        assert!(letter > 'p');
+        assert!(letter < Bounded::new('r', 'a', 'z').unwrap());
+        assert_eq!(letter.max_bound(), &'z');
+
+        assert_eq!(Bounded::new('z', 'a', 'z'), Err(BoundsError::TooLarge));
+        assert_eq!(Bounded::new('A', 'a', 'z'), Err(BoundsError::TooSmall));
+        assert!(letter.clamp('b').is_ok());
+    }
+
     #[test]
     fn test_create() {
         let foo = Bounded::new(3, -5, 74).unwrap();
@@ -98,20 +238,61 @@ mod tests {
 
     #[test]
     fn test_debug() {
-        let foo = Bounded { inner: 3, min: -5, max: 74 };
+        let foo = Bounded::new(3, -5, 74).unwrap();
         let out = format!("{:?}", foo);
         assert_eq!(&out, "Bounded {inner: 3, min: -5, max: 74}")
     }
 
     #[test]
     fn test_eq() {
-        let foo = Bounded { inner: 3, min: -5, max: 74 };
-        let bar = Bounded { inner: 3, min: 0, max: 74 };
+        let foo = Bounded::new(3, -5, 74).unwrap();
+        let bar = Bounded::new(3, 0, 74).unwrap();
         assert_eq!(foo, 3);
         assert_eq!(bar, 3);
         assert_eq!(foo, bar);
     }
 
+    #[test]
+    fn test_contains() {
+        let foo = Bounded::new(3, -2, 8).unwrap();
+        assert!(foo.contains(&-2));
+        assert!(foo.contains(&7));
+        assert!(!foo.contains(&8));
+        assert!(!foo.contains(&-3));
+    }
+
+    #[test]
+    fn test_with_bounds() {
+        let bounds = Bounds::new(0, 10);
+        let foo = Bounded::with_bounds(3, bounds).unwrap();
+        assert_eq!(foo, 3);
+        assert_eq!(foo.bounds(), &bounds);
+        let too_large = Bounded::with_bounds(10, bounds);
+        assert_eq!(too_large, Err(BoundsError::TooLarge));
+        let too_small = Bounded::with_bounds(-1, bounds);
+        assert_eq!(too_small, Err(BoundsError::TooSmall));
+    }
+
+    #[test]
+    fn test_from_range() {
+        let foo = Bounded::from_range(7, 0..10).unwrap();
+        assert_eq!(foo, 7);
+        assert_eq!(foo.bounds(), &Bounds::new(0, 10));
+
+        assert_eq!(Bounded::from_range(10, 0..10), Err(BoundsError::TooLarge));
+        let empty = Bounded::from_range(5, 5..5);
+        assert_eq!(empty, Err(BoundsError::InvalidBounds));
+    }
+
+    #[test]
+    fn test_min_max_with() {
+        let a = Bounded::new(3.5, 0.0, 10.0).unwrap();
+        let b = Bounded::new(-2.0, -5.0, 5.0).unwrap();
+        assert_eq!(a.max_with(b), 3.5);
+        assert_eq!(a.min_with(b), -2.0);
+        assert_eq!(a.min_with(b).bounds(), b.bounds());
+    }
+
     #[test]
     fn test_max_and_min() {
         let foo = Bounded::new(4, -3, 8).unwrap();
@@ -143,4 +324,96 @@ mod tests {
         assert_eq!(Bounded::new(-15.0, -10.0, 10.0), Err(TooSmall));
         assert_eq!(Bounded::new(15.0, -10.0, 10.0), Err(TooLarge));
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let foo = Bounded::new(3, -5, 74).unwrap();
+        let json = serde_json::to_string(&foo).unwrap();
+        assert_eq!(json, r#"{"inner":3,"min":-5,"max":74}"#);
+        let bar: Bounded<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bar, foo);
+        assert_eq!(bar.range(), foo.range());
+
+        let foo = Bounded::new(2.5, 0.0, 10.0).unwrap();
+        let json = serde_json::to_string(&foo).unwrap();
+        let bar: Bounded<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bar, foo);
+        assert_eq!(bar.range(), foo.range());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn cannot_deserialize_outside_of_bounds() {
+        let json = r#"{"inner":10,"min":0,"max":10}"#;
+        assert!(serde_json::from_str::<Bounded<u32>>(json).is_err());
+
+        let json = r#"{"inner":-1.0,"min":0.0,"max":10.0}"#;
+        assert!(serde_json::from_str::<Bounded<f64>>(json).is_err());
+
+        let json = r#"{"inner":3,"min":10,"max":0}"#;
+        assert!(serde_json::from_str::<Bounded<u32>>(json).is_err());
+    }
+
+    #[test]
+    fn test_hash() {
+        use crate::test_utils::hash_of;
+
+        let foo = Bounded::new(3, -5, 74).unwrap();
+        let bar = Bounded::new(3, 0, 74).unwrap();
+        assert_eq!(foo, bar);
+        assert_eq!(hash_of(&foo), hash_of(&bar));
+    }
+
+    #[test]
+    fn test_display() {
+        let foo = Bounded::new(3.5, -5.0, 74.0).unwrap();
+        assert_eq!(format!("{}", foo), "3.5");
+        assert_eq!(format!("{:.2}", foo), "3.50");
+    }
+
+    #[test]
+    fn test_neg() {
+        use BoundsError::*;
+        let foo = Bounded::new(40, -100, 50).unwrap();
+        assert_eq!((-foo).unwrap(), -40);
+
+        let bar = Bounded::new(-80.0, -100.0, 50.0).unwrap();
+        assert_eq!(-bar, Err(TooLarge));
+
+        let baz = Bounded::new(80.0, -50.0, 100.0).unwrap();
+        assert_eq!(-baz, Err(TooSmall));
+    }
+
+    #[test]
+    fn test_try_new() {
+        use BoundsError::*;
+        assert_eq!(Bounded::try_new(3, -5, 74).unwrap(), 3);
+        assert_eq!(Bounded::try_new(80, -5, 74), Err(TooLarge));
+        assert_eq!(Bounded::try_new(3, 5, 5), Err(InvalidBounds));
+        assert_eq!(Bounded::try_new(3, 10, 5), Err(InvalidBounds));
+    }
+
+    #[test]
+    fn test_try_from_tuple() {
+        let foo: Bounded<i32> = (5, 0, 10).try_into().unwrap();
+        assert_eq!(foo, 5);
+        assert_eq!(foo.bounds(), &Bounds::new(0, 10));
+
+        let too_large: Result<Bounded<i32>, _> = (10, 0, 10).try_into();
+        assert_eq!(too_large, Err(BoundsError::TooLarge));
+        let invalid = Bounded::try_from((5.0, 10.0, 0.0));
+        assert_eq!(invalid, Err(BoundsError::InvalidBounds));
+
+        fn parse(value: (u8, u8, u8)) -> Result<u8, BoundsError> {
+            let bounded: Bounded<u8> = value.try_into()?;
+            Ok(bounded.into_inner())
+        }
+        assert_eq!(parse((3, 1, 4)), Ok(3));
+        assert_eq!(parse((0, 1, 4)), Err(BoundsError::TooSmall));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_use_invalid_bounds() { let _ = Bounded::new(3, 10, 5); }
 }
@@ -1,27 +1,73 @@
-use std::cmp::{Ord, Ordering, PartialOrd};
-use std::ops::{Add, Div, Mul, Range, Rem, Sub};
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Deref, Div, Mul, Neg, Range, RangeInclusive, Rem, Sub};
+use core::str::FromStr;
 
-use super::BoundsError;
+use super::{BoundsError, ConvertError, ParseError};
+use crate::clamped::Clamped;
+use crate::guard::{ClampGuard, OutOfBounds};
+use crate::iter::{All, Steps};
 use crate::macros::*;
 
+// The only real difference between the exclusive and inclusive types is
+// whether MAX itself is a valid value.
+macro_rules! impl_range {
+    (Range, $inner:ty, $uinner:ty) => {
+        pub fn range(&self) -> Range<$inner> { MIN..MAX }
+        pub fn is_at_max(&self) -> bool { self.0 == MAX - 1 }
+        const fn too_large(inner: $inner) -> bool { inner >= MAX }
+        const fn largest() -> $inner { MAX - 1 }
+
+        /// Returns an iterator over every value in `MIN..MAX`.
+        pub fn all() -> All<Self> {
+            let () = Self::VALID_BOUNDS;
+            let last = MAX.abs_diff(MIN) as u128 - 1;
+            All::new(last, |offset| Self(MIN.wrapping_add(offset as $inner)))
+        }
+
+        /// Maps `t` from `0.0..=1.0` into the range, rounding to the nearest
+        /// value. `t` is clamped if it falls outside of `0.0..=1.0`, and as
+        /// `MAX` itself is out of range, `1.0` maps to `MAX - 1`.
+        pub fn from_normalized(t: f64) -> Self {
+            Self(Self::denormalize(t).min(MAX - 1))
+        }
+    };
+    (RangeInclusive, $inner:ty, $uinner:ty) => {
+        pub fn range(&self) -> RangeInclusive<$inner> { MIN..=MAX }
+        pub fn is_at_max(&self) -> bool { self.0 == MAX }
+        const fn too_large(inner: $inner) -> bool { inner > MAX }
+        const fn largest() -> $inner { MAX }
+
+        /// Returns an iterator over every value in `MIN..=MAX`.
+        pub fn all() -> All<Self> {
+            let () = Self::VALID_BOUNDS;
+            let last = MAX.abs_diff(MIN) as u128;
+            All::new(last, |offset| Self(MIN.wrapping_add(offset as $inner)))
+        }
+
+        /// Maps `t` from `0.0..=1.0` into the range, rounding to the nearest
+        /// value. `t` is clamped if it falls outside of `0.0..=1.0`.
+        pub fn from_normalized(t: f64) -> Self { Self(Self::denormalize(t)) }
+    };
+}
+
 macro_rules! impl_create {
-    ($type:ty, $inner:ty) => {
+    ($type:ty, $inner:ty, $uinner:ty, $range:ident) => {
         impl<const MIN: $inner, const MAX: $inner> $type {
             pub fn new(inner: $inner) -> Result<Self, BoundsError> {
                 Self::try_from(inner)
             }
             pub fn inner(&self) -> $inner { self.0 }
-            pub fn range(&self) -> Range<$inner> { MIN..MAX }
-            pub fn min_bound(&self) -> $inner { MIN }
-            pub fn max_bound(&self) -> $inner { MAX }
-        }
 
-        impl<const MIN: $inner, const MAX: $inner> TryFrom<$inner> for $type {
-            type Error = BoundsError;
-            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
-                assert!(MIN < MAX, "MIN must be less than MAX");
+            /// A `const` version of [`Self::new`], for use in `const` and
+            /// `static` items.
+            pub const fn try_new_const(
+                inner: $inner,
+            ) -> Result<Self, BoundsError> {
+                let () = Self::VALID_BOUNDS;
 
-                if inner >= MAX {
+                if Self::too_large(inner) {
                     Err(BoundsError::TooLarge)
                 } else if inner < MIN {
                     Err(BoundsError::TooSmall)
@@ -29,13 +75,81 @@ macro_rules! impl_create {
                     Ok(Self(inner))
                 }
             }
+
+            /// A `const` version of [`Self::inner`].
+            pub const fn inner_const(&self) -> $inner { self.0 }
+
+            pub fn min_bound(&self) -> $inner { MIN }
+            pub fn max_bound(&self) -> $inner { MAX }
+            pub fn is_at_min(&self) -> bool { self.0 == MIN }
+            impl_range!($range, $inner, $uinner);
+
+            // The distances are returned unsigned, as the bounds of a signed
+            // type can be further apart than its own MAX. As with is_at_max(),
+            // the exclusive types measure up to MAX - 1.
+            pub fn dist_to_min(&self) -> $uinner { self.0.abs_diff(MIN) }
+            pub fn dist_to_max(&self) -> $uinner {
+                Self::largest().abs_diff(self.0)
+            }
+
+            /// Whether `inner` is in range, i.e. whether [`TryFrom::try_from`]
+            /// would succeed.
+            pub fn contains(inner: $inner) -> bool {
+                inner >= MIN && !Self::too_large(inner)
+            }
+
+            /// An alias for [`TryFrom::try_from`].
+            pub fn clamp(inner: $inner) -> Result<Self, BoundsError> {
+                Self::try_from(inner)
+            }
+
+            /// Converts from any integer type, checking that `value` fits
+            /// into the inner type before checking it against the bounds.
+            ///
+            /// This isn't provided through `TryFrom`, as having more than one
+            /// `TryFrom` impl would break type inference for integer literals.
+            pub fn try_from_int<T>(value: T) -> Result<Self, ConvertError>
+            where
+                T: TryInto<$inner>,
+                ConvertError: From<T::Error>,
+            {
+                Ok(Self::try_from(value.try_into()?)?)
+            }
+
+            /// As [`TryFrom::try_from`], but errors also include how far
+            /// `inner` lies past the violated bound. For the exclusive types,
+            /// this is measured from `MAX - 1`.
+            pub fn try_from_verbose(
+                inner: $inner,
+            ) -> Result<Self, (BoundsError, $uinner)> {
+                Self::try_from(inner).map_err(|err| match err {
+                    BoundsError::TooSmall => (err, MIN.abs_diff(inner)),
+                    _ => (err, inner.abs_diff(Self::largest())),
+                })
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> TryFrom<$inner> for $type {
+            type Error = BoundsError;
+            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
+                Self::try_new_const(inner)
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> FromStr for $type {
+            type Err = ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::try_from(s.parse::<$inner>()?)?)
+            }
         }
     };
 }
 
 macro_rules! impl_all {
-    ($type:ty, $other:ty, $inner:ty) => {
-        impl_create!($type, $inner);
+    ($type:ty, $other:ty, $inner:ty, $uinner:ty, $range:ident) => {
+        impl_create!($type, $inner, $uinner, $range);
+        impl_valid_bounds!($type, $inner);
+        impl_width!($type, $inner, $uinner, Self::largest());
 
         impl_arith!($type, $other, $inner, Add, add, |this, other| this
             + other);
@@ -48,57 +162,357 @@ macro_rules! impl_all {
         impl_arith!($type, $other, $inner, Sub, sub, |this, other| this
             - other);
 
+        // Unlike the arithmetic operators, these methods keep the result
+        // bounded, returning None if it would fall out of range (or if the
+        // inner type would overflow or divide by zero).
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            pub fn checked_add(self, rhs: $inner) -> Option<Self> {
+                Self::try_from(self.0.checked_add(rhs)?).ok()
+            }
+            pub fn checked_sub(self, rhs: $inner) -> Option<Self> {
+                Self::try_from(self.0.checked_sub(rhs)?).ok()
+            }
+            pub fn checked_mul(self, rhs: $inner) -> Option<Self> {
+                Self::try_from(self.0.checked_mul(rhs)?).ok()
+            }
+            pub fn checked_div(self, rhs: $inner) -> Option<Self> {
+                Self::try_from(self.0.checked_div(rhs)?).ok()
+            }
+            pub fn checked_rem(self, rhs: $inner) -> Option<Self> {
+                Self::try_from(self.0.checked_rem(rhs)?).ok()
+            }
+        }
+
+        // Like the checked methods, but reporting which bound was crossed.
+        // When the inner type overflows, its saturating result shows which
+        // direction it went in. Dividing by zero is an error of its own.
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            pub fn try_add(self, rhs: $inner) -> Result<Self, BoundsError> {
+                Self::revalidate(
+                    self.0.checked_add(rhs),
+                    self.0.saturating_add(rhs),
+                )
+            }
+            pub fn try_sub(self, rhs: $inner) -> Result<Self, BoundsError> {
+                Self::revalidate(
+                    self.0.checked_sub(rhs),
+                    self.0.saturating_sub(rhs),
+                )
+            }
+            pub fn try_mul(self, rhs: $inner) -> Result<Self, BoundsError> {
+                Self::revalidate(
+                    self.0.checked_mul(rhs),
+                    self.0.saturating_mul(rhs),
+                )
+            }
+            pub fn try_div(self, rhs: $inner) -> Result<Self, BoundsError> {
+                if rhs == 0 {
+                    return Err(BoundsError::DivByZero);
+                }
+                Self::revalidate(
+                    self.0.checked_div(rhs),
+                    self.0.saturating_div(rhs),
+                )
+            }
+
+            fn revalidate(
+                checked: Option<$inner>,
+                saturated: $inner,
+            ) -> Result<Self, BoundsError> {
+                match checked {
+                    Some(inner) => Self::try_from(inner),
+                    None if saturated == <$inner>::MAX => {
+                        Err(BoundsError::TooLarge)
+                    },
+                    None => Err(BoundsError::TooSmall),
+                }
+            }
+        }
+
         impl_ord!($type, $other, $inner);
+        impl_hash!($type, $inner);
+        impl_bound_consts!($type, $inner);
+        impl_as_float!($type, $inner);
+        impl_default!($type, $inner);
+        impl_display!($type, $inner);
+        impl_deref!($type, $inner);
+        impl_into_inner!($type, $inner);
+        impl_min_max!($type, $inner);
+        impl_apply!(
+            $type,
+            $inner,
+            Result<$inner, BoundsError>,
+            |value| Self::try_from(value).map(Self::into_inner)
+        );
+        impl_map!(
+            $type,
+            $inner,
+            try_map,
+            Result<Self, BoundsError>,
+            Self::try_from
+        );
+        impl_clamped!(
+            $type,
+            $inner,
+            Result<Self, BoundsError>,
+            Self::try_from
+        );
+        impl_inner_mut_bounded!($type, $inner);
+        impl_rebound!(
+            $type,
+            $other,
+            $inner,
+            Result<$other, BoundsError>,
+            <$other>::try_from
+        );
+        impl_midpoint!($type, $inner, |inner| Self::try_from(inner).unwrap());
+        impl_step!($type, $inner);
+        impl_normalize!($type, $other, $inner);
+
+        #[cfg(feature = "num-traits")]
+        impl_num_bounded!(
+            $type,
+            $inner,
+            Self::try_from(MIN).unwrap(),
+            Self::try_from(Self::largest()).unwrap()
+        );
+
+        #[cfg(feature = "serde")]
+        impl_serde!($type, $inner, |inner| Self::try_from(inner)
+            .map_err(serde::de::Error::custom));
+    };
+}
+
+// Implementations that only make sense for signed integers.
+macro_rules! impl_signed {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> Neg for $type {
+            type Output = Result<Self, BoundsError>;
+            fn neg(self) -> Self::Output {
+                // -$inner::MIN can't be represented, but is too large anyway
+                let inner = self.0.checked_neg().ok_or(BoundsError::TooLarge)?;
+                Self::try_from(inner)
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// The absolute value, or an error if it would be out of range.
+            pub fn abs(self) -> Result<Self, BoundsError> {
+                if self.0 < 0 {
+                    -self
+                } else {
+                    Ok(self)
+                }
+            }
+        }
     };
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedU8<const MIN: u8, const MAX: u8>(u8);
-impl_all!(BoundedU8<MIN, MAX>, BoundedU8<OTHER_MIN, OTHER_MAX>, u8);
+impl_all!(BoundedU8<MIN, MAX>, BoundedU8<OTHER_MIN, OTHER_MAX>, u8, u8, Range);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedU16<const MIN: u16, const MAX: u16>(u16);
-impl_all!(BoundedU16<MIN, MAX>, BoundedU16<OTHER_MIN, OTHER_MAX>, u16);
+impl_all!(BoundedU16<MIN, MAX>, BoundedU16<OTHER_MIN, OTHER_MAX>, u16, u16, Range);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedU32<const MIN: u32, const MAX: u32>(u32);
-impl_all!(BoundedU32<MIN, MAX>, BoundedU32<OTHER_MIN, OTHER_MAX>, u32);
+impl_all!(BoundedU32<MIN, MAX>, BoundedU32<OTHER_MIN, OTHER_MAX>, u32, u32, Range);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedU64<const MIN: u64, const MAX: u64>(u64);
-impl_all!(BoundedU64<MIN, MAX>, BoundedU64<OTHER_MIN, OTHER_MAX>, u64);
+impl_all!(BoundedU64<MIN, MAX>, BoundedU64<OTHER_MIN, OTHER_MAX>, u64, u64, Range);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedU128<const MIN: u128, const MAX: u128>(u128);
-impl_all!(BoundedU128<MIN, MAX>, BoundedU128<OTHER_MIN, OTHER_MAX>, u128);
+impl_all!(
+    BoundedU128<MIN, MAX>,
+    BoundedU128<OTHER_MIN, OTHER_MAX>,
+    u128,
+    u128,
+    Range
+);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedUSize<const MIN: usize, const MAX: usize>(usize);
-impl_all!(BoundedUSize<MIN, MAX>, BoundedUSize<OTHER_MIN, OTHER_MAX>, usize);
+impl_all!(
+    BoundedUSize<MIN, MAX>,
+    BoundedUSize<OTHER_MIN, OTHER_MAX>,
+    usize,
+    usize,
+    Range
+);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedI8<const MIN: i8, const MAX: i8>(i8);
-impl_all!(BoundedI8<MIN, MAX>, BoundedI8<OTHER_MIN, OTHER_MAX>, i8);
+impl_all!(BoundedI8<MIN, MAX>, BoundedI8<OTHER_MIN, OTHER_MAX>, i8, u8, Range);
+impl_signed!(BoundedI8<MIN, MAX>, i8);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedI16<const MIN: i16, const MAX: i16>(i16);
-impl_all!(BoundedI16<MIN, MAX>, BoundedI16<OTHER_MIN, OTHER_MAX>, i16);
+impl_all!(BoundedI16<MIN, MAX>, BoundedI16<OTHER_MIN, OTHER_MAX>, i16, u16, Range);
+impl_signed!(BoundedI16<MIN, MAX>, i16);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedI32<const MIN: i32, const MAX: i32>(i32);
-impl_all!(BoundedI32<MIN, MAX>, BoundedI32<OTHER_MIN, OTHER_MAX>, i32);
+impl_all!(BoundedI32<MIN, MAX>, BoundedI32<OTHER_MIN, OTHER_MAX>, i32, u32, Range);
+impl_signed!(BoundedI32<MIN, MAX>, i32);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedI64<const MIN: i64, const MAX: i64>(i64);
-impl_all!(BoundedI64<MIN, MAX>, BoundedI64<OTHER_MIN, OTHER_MAX>, i64);
+impl_all!(BoundedI64<MIN, MAX>, BoundedI64<OTHER_MIN, OTHER_MAX>, i64, u64, Range);
+impl_signed!(BoundedI64<MIN, MAX>, i64);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedI128<const MIN: i128, const MAX: i128>(i128);
-impl_all!(BoundedI128<MIN, MAX>, BoundedI128<OTHER_MIN, OTHER_MAX>, i128);
+impl_all!(
+    BoundedI128<MIN, MAX>,
+    BoundedI128<OTHER_MIN, OTHER_MAX>,
+    i128,
+    u128,
+    Range
+);
+impl_signed!(BoundedI128<MIN, MAX>, i128);
 
 #[derive(Debug, Clone, Copy)]
 pub struct BoundedISize<const MIN: isize, const MAX: isize>(isize);
-impl_all!(BoundedISize<MIN, MAX>, BoundedISize<OTHER_MIN, OTHER_MAX>, isize);
+impl_all!(
+    BoundedISize<MIN, MAX>,
+    BoundedISize<OTHER_MIN, OTHER_MAX>,
+    isize,
+    usize,
+    Range
+);
+impl_signed!(BoundedISize<MIN, MAX>, isize);
+
+// Inclusive variants, where MAX is considered to be within bounds.
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveU8<const MIN: u8, const MAX: u8>(u8);
+impl_all!(
+    BoundedInclusiveU8<MIN, MAX>,
+    BoundedInclusiveU8<OTHER_MIN, OTHER_MAX>,
+    u8,
+    u8,
+    RangeInclusive
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveU16<const MIN: u16, const MAX: u16>(u16);
+impl_all!(
+    BoundedInclusiveU16<MIN, MAX>,
+    BoundedInclusiveU16<OTHER_MIN, OTHER_MAX>,
+    u16,
+    u16,
+    RangeInclusive
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveU32<const MIN: u32, const MAX: u32>(u32);
+impl_all!(
+    BoundedInclusiveU32<MIN, MAX>,
+    BoundedInclusiveU32<OTHER_MIN, OTHER_MAX>,
+    u32,
+    u32,
+    RangeInclusive
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveU64<const MIN: u64, const MAX: u64>(u64);
+impl_all!(
+    BoundedInclusiveU64<MIN, MAX>,
+    BoundedInclusiveU64<OTHER_MIN, OTHER_MAX>,
+    u64,
+    u64,
+    RangeInclusive
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveU128<const MIN: u128, const MAX: u128>(u128);
+impl_all!(
+    BoundedInclusiveU128<MIN, MAX>,
+    BoundedInclusiveU128<OTHER_MIN, OTHER_MAX>,
+    u128,
+    u128,
+    RangeInclusive
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveUSize<const MIN: usize, const MAX: usize>(usize);
+impl_all!(
+    BoundedInclusiveUSize<MIN, MAX>,
+    BoundedInclusiveUSize<OTHER_MIN, OTHER_MAX>,
+    usize,
+    usize,
+    RangeInclusive
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveI8<const MIN: i8, const MAX: i8>(i8);
+impl_all!(
+    BoundedInclusiveI8<MIN, MAX>,
+    BoundedInclusiveI8<OTHER_MIN, OTHER_MAX>,
+    i8,
+    u8,
+    RangeInclusive
+);
+impl_signed!(BoundedInclusiveI8<MIN, MAX>, i8);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveI16<const MIN: i16, const MAX: i16>(i16);
+impl_all!(
+    BoundedInclusiveI16<MIN, MAX>,
+    BoundedInclusiveI16<OTHER_MIN, OTHER_MAX>,
+    i16,
+    u16,
+    RangeInclusive
+);
+impl_signed!(BoundedInclusiveI16<MIN, MAX>, i16);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveI32<const MIN: i32, const MAX: i32>(i32);
+impl_all!(
+    BoundedInclusiveI32<MIN, MAX>,
+    BoundedInclusiveI32<OTHER_MIN, OTHER_MAX>,
+    i32,
+    u32,
+    RangeInclusive
+);
+impl_signed!(BoundedInclusiveI32<MIN, MAX>, i32);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveI64<const MIN: i64, const MAX: i64>(i64);
+impl_all!(
+    BoundedInclusiveI64<MIN, MAX>,
+    BoundedInclusiveI64<OTHER_MIN, OTHER_MAX>,
+    i64,
+    u64,
+    RangeInclusive
+);
+impl_signed!(BoundedInclusiveI64<MIN, MAX>, i64);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveI128<const MIN: i128, const MAX: i128>(i128);
+impl_all!(
+    BoundedInclusiveI128<MIN, MAX>,
+    BoundedInclusiveI128<OTHER_MIN, OTHER_MAX>,
+    i128,
+    u128,
+    RangeInclusive
+);
+impl_signed!(BoundedInclusiveI128<MIN, MAX>, i128);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedInclusiveISize<const MIN: isize, const MAX: isize>(isize);
+impl_all!(
+    BoundedInclusiveISize<MIN, MAX>,
+    BoundedInclusiveISize<OTHER_MIN, OTHER_MAX>,
+    isize,
+    usize,
+    RangeInclusive
+);
+impl_signed!(BoundedInclusiveISize<MIN, MAX>, isize);
 
 #[cfg(test)]
 mod tests {
@@ -182,15 +596,540 @@ mod tests {
         let _ = BoundedI128::<-10, 10>::try_from(-5).unwrap();
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_only_writes_inner() {
+        let a = BoundedU32::<2, 8>::new(5).unwrap();
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, "5");
+
+        let b: BoundedU32<2, 8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn cannot_deserialize_outside_of_bounds() {
+        assert!(serde_json::from_str::<BoundedU32<3, 7>>("10").is_err());
+        assert!(serde_json::from_str::<BoundedU32<3, 7>>("2").is_err());
+        assert!(serde_json::from_str::<BoundedU32<3, 7>>("7").is_err());
+        assert!(serde_json::from_str::<BoundedU32<3, 7>>("6").is_ok());
+    }
+
+    #[test]
+    fn hash_matches_eq() {
+        use crate::test_utils::hash_of;
+
+        let a = BoundedU8::<0, 16>::new(5).unwrap();
+        let b = BoundedU8::<2, 10>::new(5).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn can_be_used_as_key() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        for i in 0..16 {
+            map.insert(BoundedU8::<0, 16>::new(i).unwrap(), i * 2);
+        }
+        assert_eq!(map[&BoundedU8::new(7).unwrap()], 14);
+    }
+
+    #[test]
+    fn default_is_min() {
+        assert_eq!(BoundedU32::<2, 8>::default(), 2);
+        assert_eq!(BoundedU8::<0, 10>::default(), 0);
+        assert_eq!(BoundedI32::<-10, 10>::default(), -10);
+        assert_eq!(BoundedI64::<3, 5>::default(), 3);
+    }
+
+    #[test]
+    fn display_shows_inner() {
+        let a = BoundedI32::<-10, 10>::new(-5).unwrap();
+        assert_eq!(format!("{}", a), "-5");
+        assert_eq!(format!("{:>4}", a), "  -5");
+        assert_eq!(format!("{:?}", a), "BoundedI32(-5)");
+    }
+
+    #[test]
+    fn cannot_parse_outside_of_bounds() {
+        use BoundsError::*;
+        assert_eq!("5".parse::<BoundedU32<3, 7>>().unwrap(), 5);
+        assert_eq!(
+            "10".parse::<BoundedU32<3, 7>>(),
+            Err(ParseError::Bounds(TooLarge))
+        );
+        assert_eq!(
+            "-12".parse::<BoundedI32<-10, 10>>(),
+            Err(ParseError::Bounds(TooSmall))
+        );
+        assert!(matches!(
+            "abc".parse::<BoundedU32<3, 7>>(),
+            Err(ParseError::Int(_))
+        ));
+    }
+
+    #[test]
+    fn neg_is_checked() {
+        use BoundsError::*;
+        let a = BoundedI32::<-100, 50>::new(40).unwrap();
+        assert_eq!((-a).unwrap(), -40);
+
+        let b = BoundedI32::<-100, 50>::new(-80).unwrap();
+        assert_eq!(-b, Err(TooLarge));
+
+        let c = BoundedI32::<-50, 100>::new(80).unwrap();
+        assert_eq!(-c, Err(TooSmall));
+
+        let d = BoundedI8::<-128, 100>::new(-128).unwrap();
+        assert_eq!(-d, Err(TooLarge));
+    }
+
+    #[test]
+    fn inclusive_accepts_max() {
+        use BoundsError::*;
+        assert_eq!(BoundedU32::<0, 100>::try_from(100), Err(TooLarge));
+        assert_eq!(BoundedInclusiveU32::<0, 100>::try_from(100).unwrap(), 100);
+        assert_eq!(BoundedInclusiveU32::<0, 100>::try_from(101), Err(TooLarge));
+        assert_eq!(BoundedInclusiveI8::<-10, 10>::try_from(-11), Err(TooSmall));
+        assert_eq!(BoundedInclusiveI8::<-10, 10>::try_from(-10).unwrap(), -10);
+    }
+
+    #[test]
+    fn inclusive_range_is_inclusive() {
+        let foo = BoundedInclusiveISize::<-3, 8>::try_from(4).unwrap();
+        assert_eq!(foo.min_bound(), -3);
+        assert_eq!(foo.max_bound(), 8);
+        assert_eq!(foo.range(), -3..=8);
+    }
+
+    #[test]
+    fn full_range_bounds() {
+        use BoundsError::*;
+        type Full = BoundedInclusiveI8<{ i8::MIN }, { i8::MAX }>;
+        let a = Full::try_from(100).unwrap();
+        assert_eq!(a.checked_add(100), None);
+        assert_eq!(a.checked_sub(-100), None);
+        assert_eq!(a.checked_sub(127).unwrap(), -27);
+        assert_eq!(Full::try_from(-128).unwrap().checked_sub(1), None);
+
+        assert_eq!(a.dist_to_min(), 228);
+        assert_eq!(Full::try_from(-128).unwrap().dist_to_max(), 255);
+        assert_eq!(Full::all().count(), 256);
+
+        type Exclusive = BoundedI8<{ i8::MIN }, { i8::MAX }>;
+        assert_eq!(Exclusive::try_from(127), Err(TooLarge));
+        assert_eq!(Exclusive::try_from(-128).unwrap().dist_to_max(), 254);
+    }
+
+    #[test]
+    fn width_and_cardinality() {
+        assert_eq!(BoundedU8::<0, 255>::width(), 255);
+        assert_eq!(BoundedU8::<0, 255>::cardinality(), 255);
+        assert_eq!(BoundedInclusiveU8::<0, 255>::cardinality(), 256);
+        assert_eq!(BoundedInclusiveI64::<-3, 3>::width(), 6u64);
+        assert_eq!(BoundedInclusiveI64::<-3, 3>::cardinality(), 7);
+
+        // handy for sizing lookup tables
+        let table = [0u8; BoundedU32::<10, 20>::cardinality() as usize];
+        assert_eq!(table.len(), 10);
+    }
+
+    #[test]
+    fn is_at_bound() {
+        let a = BoundedU32::<2, 8>::clamp(7).unwrap();
+        assert!(a.is_at_max());
+        assert!(!a.is_at_min());
+        assert!(BoundedU32::<2, 8>::clamp(2).unwrap().is_at_min());
+        assert_eq!(BoundedU32::<2, 8>::clamp(8), Err(BoundsError::TooLarge));
+
+        let b = BoundedInclusiveU32::<2, 8>::clamp(8).unwrap();
+        assert!(b.is_at_max());
+        let c = BoundedInclusiveU32::<2, 8>::clamp(7).unwrap();
+        assert!(!c.is_at_max());
+    }
+
+    #[test]
+    fn contains() {
+        assert!(BoundedI32::<-2, 8>::contains(-2));
+        assert!(BoundedI32::<-2, 8>::contains(7));
+        assert!(!BoundedI32::<-2, 8>::contains(8));
+        assert!(!BoundedI32::<-2, 8>::contains(-3));
+
+        assert!(BoundedInclusiveI32::<-2, 8>::contains(8));
+        assert!(!BoundedInclusiveI32::<-2, 8>::contains(9));
+        assert!(BoundedInclusiveU8::<0, 255>::contains(255));
+    }
+
+    #[test]
+    fn dist_to_bounds() {
+        let a = BoundedU32::<2, 8>::try_from(2).unwrap();
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (0, 5));
+        let a = BoundedU32::<2, 8>::try_from(7).unwrap();
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (5, 0));
+        let a = BoundedInclusiveU32::<2, 8>::try_from(8).unwrap();
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (6, 0));
+
+        let a = BoundedInclusiveI8::<-128, 127>::try_from(127).unwrap();
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (255, 0));
+        let a = BoundedI8::<-128, 127>::try_from(-128).unwrap();
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (0, 254));
+    }
+
+    #[test]
+    fn try_from_verbose() {
+        use BoundsError::*;
+
+        type Foo = BoundedU8<5, 10>;
+        assert_eq!(Foo::try_from_verbose(7).unwrap(), 7);
+        assert_eq!(Foo::try_from_verbose(2), Err((TooSmall, 3)));
+        assert_eq!(Foo::try_from_verbose(10), Err((TooLarge, 1)));
+        assert_eq!(Foo::try_from_verbose(13), Err((TooLarge, 4)));
+
+        let inclusive = BoundedInclusiveU8::<5, 10>::try_from_verbose(13);
+        assert_eq!(inclusive, Err((TooLarge, 3)));
+
+        let signed = BoundedI8::<0, 10>::try_from_verbose(i8::MIN);
+        assert_eq!(signed, Err((TooSmall, 128)));
+    }
+
+    #[test]
+    fn try_from_int_wider() {
+        let valid = BoundedU8::<0, 100>::try_from_int(50u32);
+        assert_eq!(valid.unwrap(), 50);
+
+        let out_of_bounds = BoundedU8::<0, 100>::try_from_int(200u32);
+        let err = ConvertError::Bounds(BoundsError::TooLarge);
+        assert_eq!(out_of_bounds, Err(err));
+
+        let too_wide = BoundedU8::<0, 100>::try_from_int(300u32);
+        assert!(matches!(too_wide, Err(ConvertError::Int(_))));
+
+        let negative = BoundedU8::<0, 100>::try_from_int(-1i64);
+        assert!(matches!(negative, Err(ConvertError::Int(_))));
+    }
+
+    #[test]
+    fn try_from_int_narrower() {
+        let valid = BoundedInclusiveI64::<-10, 10>::try_from_int(10u8);
+        assert_eq!(valid.unwrap(), 10);
+
+        let too_small = BoundedI64::<-10, 10>::try_from_int(-11i8);
+        let err = ConvertError::Bounds(BoundsError::TooSmall);
+        assert_eq!(too_small, Err(err));
+
+        let same = BoundedI64::<-10, 10>::try_from_int(3i64);
+        assert_eq!(same.unwrap(), 3);
+    }
+
+    #[test]
+    fn inner_mut() {
+        use crate::guard::OutOfBounds;
+
+        let mut a = BoundedU8::<0, 10>::try_from(5).unwrap();
+        {
+            let mut inner = a.inner_mut();
+            *inner += 20;
+            *inner -= 18;
+        }
+        assert_eq!(a, 7);
+
+        // out of range changes are discarded
+        *a.inner_mut() = 10;
+        assert_eq!(a, 7);
+        *a.inner_mut_with(OutOfBounds::Reset) += 5;
+        assert_eq!(a, 7);
+
+        let mut b = BoundedInclusiveU8::<0, 10>::try_from(5).unwrap();
+        *b.inner_mut_with(OutOfBounds::Panic) = 10;
+        assert_eq!(b, 10);
+    }
+
+    #[test]
+    fn rebound() {
+        let a = BoundedU32::<0, 1000>::try_from(500).unwrap();
+        assert_eq!(a.rebound::<0, 100>(), Err(BoundsError::TooLarge));
+        assert_eq!(a.rebound::<600, 700>(), Err(BoundsError::TooSmall));
+        assert_eq!(a.rebound::<0, 500>(), Err(BoundsError::TooLarge));
+        assert_eq!(a.rebound::<400, 2000>().unwrap(), 500);
+
+        let b = BoundedInclusiveU32::<0, 1000>::try_from(500).unwrap();
+        assert_eq!(b.rebound::<0, 500>().unwrap(), 500);
+    }
+
+    #[test]
+    fn abs() {
+        let a = BoundedI32::<-100, 50>::try_from(-30).unwrap();
+        assert_eq!(a.abs().unwrap(), 30);
+        let b = BoundedI32::<-100, 50>::try_from(-80).unwrap();
+        assert_eq!(b.abs(), Err(BoundsError::TooLarge));
+        let c = BoundedInclusiveI8::<-128, 127>::try_from(-128).unwrap();
+        assert_eq!(c.abs(), Err(BoundsError::TooLarge));
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(BoundedU8::<0, 255>::midpoint_value(), 127);
+        assert_eq!(BoundedU8::<0, 255>::midpoint(), 127);
+        assert_eq!(BoundedU8::<200, 255>::midpoint(), 227);
+        assert_eq!(BoundedInclusiveU8::<0, 255>::midpoint(), 127);
+        assert_eq!(BoundedI8::<-128, 127>::midpoint(), -1);
+        assert_eq!(BoundedI8::<-100, -90>::midpoint(), -95);
+        assert_eq!(BoundedU32::<4, 5>::midpoint(), 4);
+    }
+
     #[test]
     #[should_panic]
-    fn cannot_use_equal_bounds() {
-        let _ = BoundedUSize::<10, 10>::try_from(5);
+    fn inner_mut_can_panic() {
+        use crate::guard::OutOfBounds;
+
+        let mut a = BoundedU8::<0, 10>::try_from(5).unwrap();
+        *a.inner_mut_with(OutOfBounds::Panic) = 10;
+    }
+
+    #[test]
+    fn try_set() {
+        let mut a = BoundedU8::<5, 10>::try_from(7).unwrap();
+        assert_eq!(a.try_set(9), Ok(()));
+        assert_eq!(a, 9);
+        assert_eq!(a.try_set(10), Err(BoundsError::TooLarge));
+        assert_eq!(a.try_set(2), Err(BoundsError::TooSmall));
+        assert_eq!(a, 9);
+    }
+
+    #[test]
+    fn deref_to_inner() {
+        let a = BoundedI8::<-10, 10>::try_from(-3).unwrap();
+        assert_eq!(*a, -3);
+        assert!(a.is_negative());
+        assert_eq!(AsRef::<i8>::as_ref(&a), &-3);
+    }
+
+    #[test]
+    fn into_inner() {
+        fn parse(value: u32) -> Result<u32, BoundsError> {
+            let bounded = BoundedU32::<2, 8>::new(value)?;
+            Ok(bounded.into())
+        }
+        assert_eq!(parse(5), Ok(5));
+        assert_eq!(BoundedI8::<-5, 5>::new(-3).unwrap().into_inner(), -3);
+        assert_eq!(u8::from(BoundedInclusiveU8::<0, 5>::new(5).unwrap()), 5);
+    }
+
+    #[test]
+    fn try_map() {
+        use BoundsError::*;
+
+        let a = BoundedU32::<2, 8>::try_from(6).unwrap();
+        assert_eq!(a.try_map(|x| x * 2), Err(TooLarge));
+        assert_eq!(a.try_map(|x| x / 6), Err(TooSmall));
+        assert_eq!(a.try_map(|x| x - 1).unwrap(), 5);
+    }
+
+    #[test]
+    fn apply() {
+        use BoundsError::*;
+        assert_eq!(BoundedU32::<2, 8>::apply(5), Ok(5));
+        assert_eq!(BoundedU32::<2, 8>::apply(8), Err(TooLarge));
+        assert_eq!(BoundedInclusiveU32::<2, 8>::apply(8), Ok(8));
+        assert_eq!(BoundedI8::<-10, 10>::apply(-11), Err(TooSmall));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let a = BoundedU32::<2, 10>::try_from(6).unwrap();
+        assert_eq!(a.normalize(), 0.5);
+        let b = BoundedInclusiveU32::<2, 10>::try_from(10).unwrap();
+        assert_eq!(b.normalize(), 1.0);
+    }
+
+    #[test]
+    fn from_normalized_respects_top_bound() {
+        assert_eq!(BoundedU32::<2, 10>::from_normalized(0.0), 2);
+        assert_eq!(BoundedU32::<2, 10>::from_normalized(0.5), 6);
+        assert_eq!(BoundedU32::<2, 10>::from_normalized(1.0), 9);
+        assert_eq!(BoundedU32::<2, 10>::from_normalized(7.0), 9);
+        assert_eq!(BoundedU32::<2, 10>::from_normalized(-7.0), 2);
+
+        assert_eq!(BoundedInclusiveU32::<2, 10>::from_normalized(1.0), 10);
+        assert_eq!(BoundedInclusiveU32::<2, 10>::from_normalized(7.0), 10);
+    }
+
+    #[test]
+    fn normalize_round_trips() {
+        for i in -100..100 {
+            let a = BoundedI32::<-100, 100>::try_from(i).unwrap();
+            let b = BoundedI32::<-100, 100>::from_normalized(a.normalize());
+            assert_eq!(a, b);
+        }
+        type Foo = BoundedInclusiveU64<3, 17>;
+        for i in 3..=17 {
+            let a = Foo::try_from(i).unwrap();
+            let b = Foo::from_normalized(a.normalize());
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_remap() {
+        let a = BoundedU32::<0, 10>::try_from(5).unwrap();
+        assert_eq!(a.remap::<0, 100>(), 50);
+
+        // the exclusive top bound is never reached
+        let b = BoundedU32::<0, 10>::try_from(9).unwrap();
+        assert_eq!(b.remap::<0, 10>(), 9);
+        assert_eq!(b.remap::<0, 100>(), 90);
+
+        let c = BoundedInclusiveU32::<0, 10>::try_from(10).unwrap();
+        assert_eq!(c.remap::<0, 100>(), 100);
+    }
+
+    #[test]
+    fn checked_arith_in_range() {
+        let a = BoundedU32::<2, 10>::try_from(4).unwrap();
+        assert_eq!(a.checked_add(5).unwrap(), 9);
+        assert_eq!(a.checked_sub(2).unwrap(), 2);
+        assert_eq!(a.checked_mul(2).unwrap(), 8);
+        assert_eq!(a.checked_div(2).unwrap(), 2);
+
+        let b = a.checked_add(2).and_then(|b| b.checked_mul(2));
+        assert!(b.is_none());
+        let c = a.checked_add(1).and_then(|c| c.checked_div(3));
+        assert!(c.is_none());
+        let d = a.checked_mul(2).and_then(|d| d.checked_sub(3));
+        assert_eq!(d.unwrap(), 5);
+    }
+
+    #[test]
+    fn try_arith_reports_the_bound() {
+        use BoundsError::*;
+
+        let a = BoundedU32::<2, 10>::try_from(4).unwrap();
+        assert_eq!(a.try_add(5).unwrap(), 9);
+        assert_eq!(a.try_add(6), Err(TooLarge));
+        assert_eq!(a.try_sub(2).unwrap(), 2);
+        assert_eq!(a.try_sub(3), Err(TooSmall));
+        assert_eq!(a.try_sub(5), Err(TooSmall));
+        assert_eq!(a.try_mul(2).unwrap(), 8);
+        assert_eq!(a.try_mul(3), Err(TooLarge));
+        assert_eq!(a.try_div(2).unwrap(), 2);
+        assert_eq!(a.try_div(3), Err(TooSmall));
+
+        let b = BoundedInclusiveI8::<-10, 10>::try_from(-5).unwrap();
+        assert_eq!(b.try_add(-6), Err(TooSmall));
+        assert_eq!(b.try_sub(-15).unwrap(), 10);
+        assert_eq!(b.try_mul(-3), Err(TooLarge));
+        assert_eq!(b.try_mul(3), Err(TooSmall));
+        assert_eq!(b.try_div(-1).unwrap(), 5);
+    }
+
+    #[test]
+    fn try_arith_reports_inner_overflow() {
+        use BoundsError::*;
+
+        type Full = BoundedInclusiveI8<{ i8::MIN }, { i8::MAX }>;
+        let a = Full::try_from(100).unwrap();
+        assert_eq!(a.try_add(100), Err(TooLarge));
+        assert_eq!(a.try_sub(-100), Err(TooLarge));
+        assert_eq!(a.try_mul(2), Err(TooLarge));
+        assert_eq!(a.try_mul(-2), Err(TooSmall));
+        assert_eq!(a.try_add(27).unwrap(), 127);
+
+        let b = Full::try_from(-128).unwrap();
+        assert_eq!(b.try_sub(1), Err(TooSmall));
+        assert_eq!(b.try_div(-1), Err(TooLarge));
+
+        let c = BoundedInclusiveU8::<0, 255>::try_from(0).unwrap();
+        assert_eq!(c.try_sub(1), Err(TooSmall));
+    }
+
+    #[test]
+    fn try_div_by_zero_is_an_error() {
+        let a = BoundedU32::<2, 10>::try_from(4).unwrap();
+        assert_eq!(a.try_div(0), Err(BoundsError::DivByZero));
+        let b = BoundedI8::<-128, 127>::try_from(-128).unwrap();
+        assert_eq!(b.try_div(0), Err(BoundsError::DivByZero));
+        assert_eq!(b.try_div(-1), Err(BoundsError::TooLarge));
+    }
+
+    #[test]
+    fn checked_arith_out_of_range() {
+        let a = BoundedU32::<2, 10>::try_from(4).unwrap();
+        assert!(a.checked_add(6).is_none());
+        assert!(a.checked_sub(3).is_none());
+        assert!(a.checked_sub(5).is_none());
+        assert!(a.checked_mul(3).is_none());
+        assert!(a.checked_div(3).is_none());
+
+        let b = BoundedInclusiveU32::<2, 10>::try_from(4).unwrap();
+        assert_eq!(b.checked_add(6).unwrap(), 10);
+        assert!(b.checked_add(7).is_none());
+
+        let c = BoundedI8::<-128, 127>::try_from(100).unwrap();
+        assert!(c.checked_add(100).is_none());
+        assert!(c.checked_mul(-2).is_none());
+    }
+
+    #[test]
+    fn checked_div_by_zero() {
+        let a = BoundedU32::<2, 10>::try_from(4).unwrap();
+        assert!(a.checked_div(0).is_none());
+        let b = BoundedI32::<-10, 10>::try_from(-4).unwrap();
+        assert!(b.checked_div(0).is_none());
+        assert_eq!(b.checked_div(-2).unwrap(), 2);
+
+        assert!(a.checked_rem(0).is_none());
+        assert!(b.checked_rem(0).is_none());
+        assert_eq!(b.checked_rem(3).unwrap(), -1);
+        // 4 % 3 = 1, which is below MIN
+        assert!(a.checked_rem(3).is_none());
+        assert_eq!(a.checked_rem(5).unwrap(), 4);
     }
 
     #[test]
     #[should_panic]
-    fn cannot_use_invalid_bounds() {
-        let _ = BoundedUSize::<15, 10>::try_from(5);
+    fn div_by_zero_panics() {
+        let _ = BoundedU32::<2, 10>::new(4).unwrap() / 0;
+    }
+
+    #[test]
+    fn test_all() {
+        let all: Vec<_> = BoundedU8::<3, 10>::all().collect();
+        assert_eq!(all, [3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(BoundedU8::<3, 10>::all().len(), 7);
+
+        let all: Vec<_> = BoundedInclusiveI8::<-2, 2>::all().rev().collect();
+        assert_eq!(all, [2, 1, 0, -1, -2]);
+        assert_eq!(BoundedInclusiveI8::<-2, 2>::all().len(), 5);
+
+        assert_eq!(BoundedInclusiveU8::<0, 255>::all().len(), 256);
+        type Foo = BoundedI128<{ i128::MIN }, { i128::MIN + 3 }>;
+        assert_eq!(Foo::all().len(), 3);
+    }
+
+    #[test]
+    fn all_from_both_ends() {
+        let mut all = BoundedU64::<3, 8>::all();
+        assert_eq!(all.next().unwrap(), 3);
+        assert_eq!(all.next_back().unwrap(), 7);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all.next_back().unwrap(), 6);
+        assert_eq!(all.next().unwrap(), 4);
+        assert_eq!(all.next().unwrap(), 5);
+        assert_eq!(all.len(), 0);
+        assert!(all.next().is_none());
+        assert!(all.next_back().is_none());
+    }
+
+    #[test]
+    fn test_step_by() {
+        type Foo = BoundedI32<-10, 10>;
+        let start = Foo::try_from(-9).unwrap();
+        let end = Foo::try_from(9).unwrap();
+        let steps: Vec<_> = Foo::step_by(start, end, 6).collect();
+        assert_eq!(steps, [-9, -3, 3]);
+        assert_eq!(Foo::step_by(end, start, 1).count(), 0);
     }
 }
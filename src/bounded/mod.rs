@@ -1,14 +1,172 @@
 //! Types that must fit within their given bounds to be constructed.
+//!
+//! The `Bounded` types treat `MAX` as exclusive (`MIN..MAX`), in the same way
+//! as the wrapping types. If `MAX` should be a valid value (as it is for the
+//! saturating types), use the `BoundedInclusive` types (`MIN..=MAX`) instead:
+//!
+//! ```rust
+//! use clamps::bounded::{BoundedInclusiveU8, BoundedU8};
+//!
+//! assert!(BoundedU8::<0, 100>::new(100).is_err());
+//! assert_eq!(BoundedInclusiveU8::<0, 100>::new(100).unwrap(), 100);
+//! ```
+//!
+//! As with the other types, the arithmetic operators return the inner type and
+//! follow its rules, so `/` and `%` panic when dividing by zero. The
+//! `checked_*` methods instead return `None` if the inner operation fails or
+//! the result would be out of range:
+//!
+//! ```rust
+//! use clamps::bounded::BoundedU32;
+//!
+//! let a = BoundedU32::<1, 10>::new(7).unwrap();
+//! assert_eq!(a.checked_div(2).unwrap(), 3);
+//! assert_eq!(a.checked_rem(0), None);
+//! ```
+//!
+//! When `MIN` is at least 1, the `NonZeroBounded` types can be used instead.
+//! These are stored as `NonZero` integers, so wrapping them in an `Option`
+//! doesn't take up any extra space:
+//!
+//! ```rust
+//! use clamps::bounded::NonZeroBoundedU32;
+//! use std::mem::size_of;
+//!
+//! type Foo = Option<NonZeroBoundedU32<1, 100>>;
+//! assert_eq!(size_of::<Foo>(), size_of::<u32>());
+//! ```
+//!
+//! The `BoundedSaturating` types are also checked on construction, but their
+//! arithmetic saturates at the (inclusive) bounds and returns `Self`, so a
+//! value can't leave its range once created:
+//!
+//! ```rust
+//! use clamps::bounded::BoundedSaturatingU8;
+//!
+//! type Volume = BoundedSaturatingU8<0, 10>;
+//! assert!(Volume::new(11).is_err());
+//!
+//! # #[cfg(not(all(feature = "strict-debug", debug_assertions)))] {
+//! let volume = Volume::new(8).unwrap() + 5;
+//! assert_eq!(volume, 10);
+//! # }
+//! ```
 
+use core::fmt;
+use core::convert::Infallible;
+use core::num::{ParseIntError, TryFromIntError};
+
+mod float;
 mod generic;
 mod int;
+mod nonzero;
+mod saturating;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum BoundsError {
     TooSmall,
     TooLarge,
+    InvalidBounds,
+    DivByZero,
+}
+
+impl fmt::Display for BoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            BoundsError::TooSmall => "value is below the minimum bound",
+            BoundsError::TooLarge => "value is above the maximum bound",
+            BoundsError::InvalidBounds => "MIN value must be less than MAX",
+            BoundsError::DivByZero => "attempted to divide by zero",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BoundsError {}
+
+/// An error returned when parsing a bounded type from a string.
+///
+/// Distinguishes between a string that isn't a valid integer, and a valid
+/// integer that lies outside of the type's bounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Int(ParseIntError),
+    Bounds(BoundsError),
 }
 
+impl From<ParseIntError> for ParseError {
+    fn from(err: ParseIntError) -> Self { ParseError::Int(err) }
+}
+
+impl From<BoundsError> for ParseError {
+    fn from(err: BoundsError) -> Self { ParseError::Bounds(err) }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Int(err) => err.fmt(f),
+            ParseError::Bounds(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Int(err) => Some(err),
+            ParseError::Bounds(err) => Some(err),
+        }
+    }
+}
+
+/// An error returned when converting into a bounded type from an integer of a
+/// different width.
+///
+/// Distinguishes between a value that doesn't fit into the inner type at all,
+/// and one that fits but lies outside of the type's bounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConvertError {
+    Int(TryFromIntError),
+    Bounds(BoundsError),
+}
+
+impl From<TryFromIntError> for ConvertError {
+    fn from(err: TryFromIntError) -> Self { ConvertError::Int(err) }
+}
+
+impl From<BoundsError> for ConvertError {
+    fn from(err: BoundsError) -> Self { ConvertError::Bounds(err) }
+}
+
+// Widening conversions can't fail, but still go through TryFrom.
+impl From<Infallible> for ConvertError {
+    fn from(err: Infallible) -> Self { match err {} }
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::Int(err) => err.fmt(f),
+            ConvertError::Bounds(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConvertError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConvertError::Int(err) => Some(err),
+            ConvertError::Bounds(err) => Some(err),
+        }
+    }
+}
 
+pub use float::*;
 pub use generic::*;
 pub use int::*;
+pub use nonzero::*;
+pub use saturating::*;
@@ -0,0 +1,416 @@
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::{
+    NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use core::ops::{Add, Div, Mul, Range, Rem, Sub};
+use core::str::FromStr;
+
+use super::{BoundsError, ConvertError, ParseError};
+use crate::clamped::Clamped;
+use crate::guard::{ClampGuard, OutOfBounds};
+use crate::iter::All;
+use crate::macros::*;
+
+// These work exactly like the exclusive bounded types, but require MIN to be
+// at least 1. The value is stored as a NonZero integer, which lets the compiler
+// use zero as a niche (e.g. for the None variant of an Option).
+macro_rules! impl_all {
+    ($type:ty, $other:ty, $inner:ty, $nonzero:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            // See impl_valid_bounds!, which this extends.
+            const VALID_BOUNDS: () = {
+                assert!(MIN < MAX, "MIN must be less than MAX");
+                assert!(MIN > 0, "MIN must be greater than zero");
+            };
+
+            pub fn new(inner: $inner) -> Result<Self, BoundsError> {
+                Self::try_from(inner)
+            }
+            pub fn inner(&self) -> $inner { self.0.get() }
+            pub fn inner_nonzero(&self) -> $nonzero { self.0 }
+
+            /// A `const` version of [`Self::new`], for use in `const` and
+            /// `static` items.
+            pub const fn try_new_const(
+                inner: $inner,
+            ) -> Result<Self, BoundsError> {
+                let () = Self::VALID_BOUNDS;
+
+                if inner >= MAX {
+                    Err(BoundsError::TooLarge)
+                } else if inner < MIN {
+                    Err(BoundsError::TooSmall)
+                } else {
+                    match <$nonzero>::new(inner) {
+                        Some(inner) => Ok(Self(inner)),
+                        None => Err(BoundsError::TooSmall),
+                    }
+                }
+            }
+
+            /// A `const` version of [`Self::inner`].
+            pub const fn inner_const(&self) -> $inner { self.0.get() }
+            pub fn range(&self) -> Range<$inner> { MIN..MAX }
+            pub fn min_bound(&self) -> $inner { MIN }
+            pub fn max_bound(&self) -> $inner { MAX }
+            pub fn is_at_min(&self) -> bool { self.inner() == MIN }
+            pub fn is_at_max(&self) -> bool { self.inner() == MAX - 1 }
+            pub fn dist_to_min(&self) -> $inner { self.inner() - MIN }
+            pub fn dist_to_max(&self) -> $inner { MAX - 1 - self.inner() }
+
+            /// Whether `inner` is in range, i.e. whether [`TryFrom::try_from`]
+            /// would succeed.
+            pub fn contains(inner: $inner) -> bool {
+                inner >= MIN && inner < MAX
+            }
+
+            /// An alias for [`TryFrom::try_from`].
+            pub fn clamp(inner: $inner) -> Result<Self, BoundsError> {
+                Self::try_from(inner)
+            }
+
+            /// Converts from any integer type, checking that `value` fits
+            /// into the inner type before checking it against the bounds.
+            ///
+            /// This isn't provided through `TryFrom`, as having more than one
+            /// `TryFrom` impl would break type inference for integer literals.
+            pub fn try_from_int<T>(value: T) -> Result<Self, ConvertError>
+            where
+                T: TryInto<$inner>,
+                ConvertError: From<T::Error>,
+            {
+                Ok(Self::try_from(value.try_into()?)?)
+            }
+
+            /// As [`TryFrom::try_from`], but errors also include how far
+            /// `inner` lies past the violated bound. As `MAX` is out of range,
+            /// this is measured from `MAX - 1`.
+            pub fn try_from_verbose(
+                inner: $inner,
+            ) -> Result<Self, (BoundsError, $inner)> {
+                Self::try_from(inner).map_err(|err| match err {
+                    BoundsError::TooSmall => (err, MIN - inner),
+                    _ => (err, inner - (MAX - 1)),
+                })
+            }
+
+            /// Returns an iterator over every value in `MIN..MAX`.
+            pub fn all() -> All<Self> {
+                let () = Self::VALID_BOUNDS;
+                let last = (MAX - MIN) as u128 - 1;
+                All::new(last, |offset| {
+                    Self::try_from(MIN + offset as $inner).unwrap()
+                })
+            }
+
+            /// Maps `t` from `0.0..=1.0` into the range, rounding to the
+            /// nearest value. `t` is clamped if it falls outside of
+            /// `0.0..=1.0`, and as `MAX` itself is out of range, `1.0` maps to
+            /// `MAX - 1`.
+            pub fn from_normalized(t: f64) -> Self {
+                // MIN is checked to be nonzero by the call to try_from
+                Self::try_from(Self::denormalize(t).min(MAX - 1)).unwrap()
+            }
+
+            pub fn checked_add(self, rhs: $inner) -> Option<Self> {
+                Self::try_from(self.inner().checked_add(rhs)?).ok()
+            }
+            pub fn checked_sub(self, rhs: $inner) -> Option<Self> {
+                Self::try_from(self.inner().checked_sub(rhs)?).ok()
+            }
+            pub fn checked_mul(self, rhs: $inner) -> Option<Self> {
+                Self::try_from(self.inner().checked_mul(rhs)?).ok()
+            }
+            pub fn checked_div(self, rhs: $inner) -> Option<Self> {
+                Self::try_from(self.inner().checked_div(rhs)?).ok()
+            }
+            pub fn checked_rem(self, rhs: $inner) -> Option<Self> {
+                Self::try_from(self.inner().checked_rem(rhs)?).ok()
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> TryFrom<$inner> for $type {
+            type Error = BoundsError;
+            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
+                Self::try_new_const(inner)
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> FromStr for $type {
+            type Err = ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::try_from(s.parse::<$inner>()?)?)
+            }
+        }
+
+        /// The default value is `MIN`, as it's the only value guaranteed to be
+        /// within bounds.
+        impl<const MIN: $inner, const MAX: $inner> Default for $type {
+            fn default() -> Self { Self::try_from(MIN).unwrap() }
+        }
+
+        impl_arith!($type, $other, $inner, Add, add, |this, other| this
+            + other);
+        impl_arith!($type, $other, $inner, Div, div, |this, other| this
+            / other);
+        impl_arith!($type, $other, $inner, Mul, mul, |this, other| this
+            * other);
+        impl_arith!($type, $other, $inner, Rem, rem, |this, other| this
+            % other);
+        impl_arith!($type, $other, $inner, Sub, sub, |this, other| this
+            - other);
+
+        impl_ord!($type, $other, $inner);
+        impl_hash!($type, $inner);
+        impl_bound_consts!($type, $inner);
+        impl_as_float!($type, $inner);
+        impl_display!($type, $inner);
+        impl_into_inner!($type, $inner);
+        impl_min_max!($type, $inner);
+        impl_apply!(
+            $type,
+            $inner,
+            Result<$inner, BoundsError>,
+            |value| Self::try_from(value).map(Self::into_inner)
+        );
+        impl_map!(
+            $type,
+            $inner,
+            try_map,
+            Result<Self, BoundsError>,
+            Self::try_from
+        );
+        impl_clamped!(
+            $type,
+            $inner,
+            Result<Self, BoundsError>,
+            Self::try_from
+        );
+        impl_inner_mut_bounded!($type, $inner);
+        impl_rebound!(
+            $type,
+            $other,
+            $inner,
+            Result<$other, BoundsError>,
+            <$other>::try_from
+        );
+        impl_midpoint!($type, $inner, |inner| Self::try_from(inner).unwrap());
+        impl_normalize!($type, $other, $inner);
+        impl_width!($type, $inner, $inner, MAX - 1);
+
+        #[cfg(feature = "num-traits")]
+        impl_num_bounded!(
+            $type,
+            $inner,
+            Self::try_from(MIN).unwrap(),
+            Self::try_from(MAX - 1).unwrap()
+        );
+
+        #[cfg(feature = "serde")]
+        impl_serde!($type, $inner, |inner| Self::try_from(inner)
+            .map_err(serde::de::Error::custom));
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NonZeroBoundedU8<const MIN: u8, const MAX: u8>(NonZeroU8);
+impl_all!(
+    NonZeroBoundedU8<MIN, MAX>,
+    NonZeroBoundedU8<OTHER_MIN, OTHER_MAX>,
+    u8,
+    NonZeroU8
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NonZeroBoundedU16<const MIN: u16, const MAX: u16>(NonZeroU16);
+impl_all!(
+    NonZeroBoundedU16<MIN, MAX>,
+    NonZeroBoundedU16<OTHER_MIN, OTHER_MAX>,
+    u16,
+    NonZeroU16
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NonZeroBoundedU32<const MIN: u32, const MAX: u32>(NonZeroU32);
+impl_all!(
+    NonZeroBoundedU32<MIN, MAX>,
+    NonZeroBoundedU32<OTHER_MIN, OTHER_MAX>,
+    u32,
+    NonZeroU32
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NonZeroBoundedU64<const MIN: u64, const MAX: u64>(NonZeroU64);
+impl_all!(
+    NonZeroBoundedU64<MIN, MAX>,
+    NonZeroBoundedU64<OTHER_MIN, OTHER_MAX>,
+    u64,
+    NonZeroU64
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NonZeroBoundedU128<const MIN: u128, const MAX: u128>(NonZeroU128);
+impl_all!(
+    NonZeroBoundedU128<MIN, MAX>,
+    NonZeroBoundedU128<OTHER_MIN, OTHER_MAX>,
+    u128,
+    NonZeroU128
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NonZeroBoundedUSize<const MIN: usize, const MAX: usize>(
+    NonZeroUsize,
+);
+impl_all!(
+    NonZeroBoundedUSize<MIN, MAX>,
+    NonZeroBoundedUSize<OTHER_MIN, OTHER_MAX>,
+    usize,
+    NonZeroUsize
+);
+
+#[cfg(test)]
+mod tests {
+    use core::mem::size_of;
+
+    use super::*;
+
+    #[test]
+    fn has_niche() {
+        assert_eq!(
+            size_of::<Option<NonZeroBoundedU32<1, 100>>>(),
+            size_of::<u32>()
+        );
+        assert_eq!(
+            size_of::<Option<NonZeroBoundedU8<3, 10>>>(),
+            size_of::<u8>()
+        );
+    }
+
+    #[test]
+    fn can_create() {
+        let a = NonZeroBoundedU32::<1, 100>::try_from(50).unwrap();
+        assert_eq!(a.inner(), 50);
+        assert_eq!(a.inner_nonzero(), NonZeroU32::new(50).unwrap());
+        assert_eq!(a.range(), 1..100);
+        assert_eq!(a + 1, 51);
+    }
+
+    #[test]
+    fn cannot_create_outside_of_bounds() {
+        use BoundsError::*;
+        assert_eq!(NonZeroBoundedU32::<1, 100>::try_from(0), Err(TooSmall));
+        assert_eq!(NonZeroBoundedU32::<1, 100>::try_from(100), Err(TooLarge));
+        assert!(NonZeroBoundedU32::<1, 100>::try_from(99).unwrap().is_at_max());
+        assert!(NonZeroBoundedU32::<1, 100>::try_from(1).unwrap().is_at_min());
+
+        let a = NonZeroBoundedU32::<1, 100>::try_from(99).unwrap();
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (98, 0));
+
+        let verbose = NonZeroBoundedU32::<1, 100>::try_from_verbose(0);
+        assert_eq!(verbose, Err((BoundsError::TooSmall, 1)));
+        let verbose = NonZeroBoundedU32::<1, 100>::try_from_verbose(103);
+        assert_eq!(verbose, Err((BoundsError::TooLarge, 4)));
+
+        let zero = NonZeroBoundedU16::<1, 10>::try_from_int(0u64);
+        assert_eq!(zero, Err(ConvertError::Bounds(BoundsError::TooSmall)));
+        let wide = NonZeroBoundedU16::<1, 10>::try_from_int(1u64 << 40);
+        assert!(matches!(wide, Err(ConvertError::Int(_))));
+
+        let mut b = NonZeroBoundedU32::<1, 100>::try_from(50).unwrap();
+        *b.inner_mut() = 0;
+        assert_eq!(b, 50);
+        *b.inner_mut() -= 49;
+        assert!(b.is_at_min());
+        assert_eq!(b.try_set(0), Err(BoundsError::TooSmall));
+        assert!(b.is_at_min());
+    }
+
+    #[test]
+    fn matches_bounded() {
+        use crate::bounded::BoundedU16;
+
+        for i in 0..20 {
+            let a = NonZeroBoundedU16::<3, 10>::try_from(i).map(|a| a.inner());
+            let b = BoundedU16::<3, 10>::try_from(i).map(|b| b.inner());
+            assert_eq!(a, b);
+            let contains = NonZeroBoundedU16::<3, 10>::contains(i);
+            assert_eq!(contains, BoundedU16::<3, 10>::contains(i));
+        }
+    }
+
+    #[test]
+    fn test_checked_arith() {
+        let a = NonZeroBoundedU32::<1, 10>::try_from(4).unwrap();
+        assert_eq!(a.checked_add(5).unwrap(), 9);
+        assert!(a.checked_sub(4).is_none());
+        assert!(a.checked_div(0).is_none());
+        assert!(a.checked_rem(0).is_none());
+    }
+
+    #[test]
+    fn test_rebound() {
+        let a = NonZeroBoundedU32::<1, 10>::try_from(4).unwrap();
+        assert_eq!(a.rebound::<1, 4>(), Err(BoundsError::TooLarge));
+        assert_eq!(a.rebound::<2, 20>().unwrap(), 4);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        assert_eq!(NonZeroBoundedU8::<1, 255>::midpoint(), 128);
+        assert_eq!(NonZeroBoundedU8::<1, 2>::midpoint(), 1);
+    }
+
+    #[test]
+    fn test_default_parse_and_display() {
+        assert_eq!(NonZeroBoundedU64::<5, 10>::default(), 5);
+        let a: NonZeroBoundedU64<5, 10> = "7".parse().unwrap();
+        assert_eq!(format!("{}", a), "7");
+        assert!("0".parse::<NonZeroBoundedU64<5, 10>>().is_err());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let a = NonZeroBoundedU32::<1, 11>::try_from(6).unwrap();
+        assert_eq!(a.normalize(), 0.5);
+        assert_eq!(NonZeroBoundedU32::<1, 11>::from_normalized(1.0), 10);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let a = NonZeroBoundedU32::<1, 100>::try_from(5).unwrap();
+        assert_eq!(serde_json::to_string(&a).unwrap(), "5");
+        let b = serde_json::from_str::<NonZeroBoundedU32<1, 100>>("0");
+        assert!(b.is_err());
+    }
+
+    #[test]
+    fn test_all() {
+        let all: Vec<_> = NonZeroBoundedU8::<1, 4>::all().collect();
+        assert_eq!(all, [1, 2, 3]);
+        assert_eq!(NonZeroBoundedU8::<1, 4>::all().len(), 3);
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let a = NonZeroBoundedU32::<1, 10>::new(7).unwrap();
+        assert_eq!(a.into_inner(), 7);
+        assert_eq!(u32::from(a), 7);
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(NonZeroBoundedU32::<1, 10>::apply(7), Ok(7));
+        let zero = NonZeroBoundedU32::<1, 10>::apply(0);
+        assert_eq!(zero, Err(BoundsError::TooSmall));
+    }
+
+    #[test]
+    fn test_width_and_cardinality() {
+        assert_eq!(NonZeroBoundedU8::<1, 255>::width(), 254);
+        assert_eq!(NonZeroBoundedU8::<1, 255>::cardinality(), 254);
+        assert_eq!(NonZeroBoundedU32::<3, 10>::cardinality(), 7);
+    }
+}
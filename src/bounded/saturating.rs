@@ -0,0 +1,391 @@
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Neg, RangeInclusive,
+    Sub, SubAssign,
+};
+use core::str::FromStr;
+
+use super::{BoundsError, ParseError};
+use crate::macros::*;
+use crate::saturating::*;
+
+// These can only be constructed from in-range values, like the bounded types,
+// but their arithmetic saturates like the saturating types rather than
+// returning the raw inner value. Both bounds are inclusive, as MAX is where
+// saturated values end up.
+//
+// Arithmetic is done by converting to $saturating, which is always lossless as
+// the two types share the same bounds.
+macro_rules! impl_all {
+    ($type:ty, $other:ty, $inner:ty, $uinner:ty, $saturating:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            pub fn new(inner: $inner) -> Result<Self, BoundsError> {
+                Self::try_from(inner)
+            }
+            pub fn inner(&self) -> $inner { self.0 }
+
+            /// A `const` version of [`Self::new`], for use in `const` and
+            /// `static` items.
+            pub const fn try_new_const(
+                inner: $inner,
+            ) -> Result<Self, BoundsError> {
+                let () = Self::VALID_BOUNDS;
+
+                if inner > MAX {
+                    Err(BoundsError::TooLarge)
+                } else if inner < MIN {
+                    Err(BoundsError::TooSmall)
+                } else {
+                    Ok(Self(inner))
+                }
+            }
+
+            /// A `const` version of [`Self::inner`].
+            pub const fn inner_const(&self) -> $inner { self.0 }
+            pub fn range(&self) -> RangeInclusive<$inner> { MIN..=MAX }
+            pub fn min_bound(&self) -> $inner { MIN }
+            pub fn max_bound(&self) -> $inner { MAX }
+            pub fn is_at_min(&self) -> bool { self.0 == MIN }
+            pub fn is_at_max(&self) -> bool { self.0 == MAX }
+
+            /// Whether `inner` is in range, i.e. whether [`TryFrom::try_from`]
+            /// would succeed.
+            pub fn contains(inner: $inner) -> bool {
+                inner >= MIN && inner <= MAX
+            }
+
+            pub fn into_saturating(self) -> $saturating {
+                <$saturating>::from(self.0)
+            }
+
+            pub fn saturating_add(self, rhs: $inner) -> Self {
+                Self::from(self.into_saturating().saturating_add(rhs))
+            }
+            pub fn saturating_sub(self, rhs: $inner) -> Self {
+                Self::from(self.into_saturating().saturating_sub(rhs))
+            }
+            pub fn saturating_mul(self, rhs: $inner) -> Self {
+                Self::from(self.into_saturating().saturating_mul(rhs))
+            }
+            pub fn saturating_div(self, rhs: $inner) -> Self {
+                Self::from(self.into_saturating().saturating_div(rhs))
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> TryFrom<$inner> for $type {
+            type Error = BoundsError;
+            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
+                Self::try_new_const(inner)
+            }
+        }
+
+        /// A saturating value with the same bounds is always in range.
+        impl<const MIN: $inner, const MAX: $inner> From<$saturating> for $type {
+            fn from(value: $saturating) -> Self { Self(value.inner()) }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> FromStr for $type {
+            type Err = ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::try_from(s.parse::<$inner>()?)?)
+            }
+        }
+
+        impl_saturating_arith!($type, $other, $inner, Add, add, saturating_add);
+        impl_saturating_arith!($type, $other, $inner, Sub, sub, saturating_sub);
+        impl_saturating_arith!($type, $other, $inner, Mul, mul, saturating_mul);
+        impl_saturating_arith!($type, $other, $inner, Div, div, saturating_div);
+
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            AddAssign,
+            add_assign,
+            saturating_add
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            SubAssign,
+            sub_assign,
+            saturating_sub
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            MulAssign,
+            mul_assign,
+            saturating_mul
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            DivAssign,
+            div_assign,
+            saturating_div
+        );
+
+        impl_valid_bounds!($type, $inner);
+        impl_ord!($type, $other, $inner);
+        impl_hash!($type, $inner);
+        impl_bound_consts!($type, $inner);
+        impl_as_float!($type, $inner);
+        impl_default!($type, $inner);
+        impl_display!($type, $inner);
+        impl_deref!($type, $inner);
+        impl_into_inner!($type, $inner);
+        impl_min_max!($type, $inner);
+        impl_width!($type, $inner, $uinner, MAX);
+        impl_apply!(
+            $type,
+            $inner,
+            Result<$inner, BoundsError>,
+            |value| Self::try_from(value).map(Self::into_inner)
+        );
+        impl_map!(
+            $type,
+            $inner,
+            try_map,
+            Result<Self, BoundsError>,
+            Self::try_from
+        );
+
+        #[cfg(feature = "serde")]
+        impl_serde!($type, $inner, |inner| Self::try_from(inner)
+            .map_err(serde::de::Error::custom));
+    };
+}
+
+// Unlike the other families, the operators return Self, as the result is
+// saturated back into range.
+macro_rules! impl_saturating_arith {
+    (
+        $type:ty, $other:ty, $inner:ty, $trait:ident, $fn:ident, $method:ident
+    ) => {
+        impl<const MIN: $inner, const MAX: $inner> $trait<$inner> for $type {
+            type Output = Self;
+            fn $fn(self, other: $inner) -> Self { self.$method(other) }
+        }
+
+        impl<
+                const MIN: $inner,
+                const MAX: $inner,
+                const OTHER_MIN: $inner,
+                const OTHER_MAX: $inner,
+            > $trait<$other> for $type
+        {
+            type Output = Self;
+            fn $fn(self, other: $other) -> Self { self.$method(other.inner()) }
+        }
+    };
+}
+
+macro_rules! impl_signed {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> Neg for $type {
+            type Output = Self;
+            fn neg(self) -> Self::Output { Self::from(-self.into_saturating()) }
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingU8<const MIN: u8, const MAX: u8>(u8);
+impl_all!(
+    BoundedSaturatingU8<MIN, MAX>,
+    BoundedSaturatingU8<OTHER_MIN, OTHER_MAX>,
+    u8,
+    u8,
+    SaturatingU8<MIN, MAX>
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingU16<const MIN: u16, const MAX: u16>(u16);
+impl_all!(
+    BoundedSaturatingU16<MIN, MAX>,
+    BoundedSaturatingU16<OTHER_MIN, OTHER_MAX>,
+    u16,
+    u16,
+    SaturatingU16<MIN, MAX>
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingU32<const MIN: u32, const MAX: u32>(u32);
+impl_all!(
+    BoundedSaturatingU32<MIN, MAX>,
+    BoundedSaturatingU32<OTHER_MIN, OTHER_MAX>,
+    u32,
+    u32,
+    SaturatingU32<MIN, MAX>
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingU64<const MIN: u64, const MAX: u64>(u64);
+impl_all!(
+    BoundedSaturatingU64<MIN, MAX>,
+    BoundedSaturatingU64<OTHER_MIN, OTHER_MAX>,
+    u64,
+    u64,
+    SaturatingU64<MIN, MAX>
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingU128<const MIN: u128, const MAX: u128>(u128);
+impl_all!(
+    BoundedSaturatingU128<MIN, MAX>,
+    BoundedSaturatingU128<OTHER_MIN, OTHER_MAX>,
+    u128,
+    u128,
+    SaturatingU128<MIN, MAX>
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingUSize<const MIN: usize, const MAX: usize>(usize);
+impl_all!(
+    BoundedSaturatingUSize<MIN, MAX>,
+    BoundedSaturatingUSize<OTHER_MIN, OTHER_MAX>,
+    usize,
+    usize,
+    SaturatingUSize<MIN, MAX>
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingI8<const MIN: i8, const MAX: i8>(i8);
+impl_all!(
+    BoundedSaturatingI8<MIN, MAX>,
+    BoundedSaturatingI8<OTHER_MIN, OTHER_MAX>,
+    i8,
+    u8,
+    SaturatingI8<MIN, MAX>
+);
+impl_signed!(BoundedSaturatingI8<MIN, MAX>, i8);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingI16<const MIN: i16, const MAX: i16>(i16);
+impl_all!(
+    BoundedSaturatingI16<MIN, MAX>,
+    BoundedSaturatingI16<OTHER_MIN, OTHER_MAX>,
+    i16,
+    u16,
+    SaturatingI16<MIN, MAX>
+);
+impl_signed!(BoundedSaturatingI16<MIN, MAX>, i16);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingI32<const MIN: i32, const MAX: i32>(i32);
+impl_all!(
+    BoundedSaturatingI32<MIN, MAX>,
+    BoundedSaturatingI32<OTHER_MIN, OTHER_MAX>,
+    i32,
+    u32,
+    SaturatingI32<MIN, MAX>
+);
+impl_signed!(BoundedSaturatingI32<MIN, MAX>, i32);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingI64<const MIN: i64, const MAX: i64>(i64);
+impl_all!(
+    BoundedSaturatingI64<MIN, MAX>,
+    BoundedSaturatingI64<OTHER_MIN, OTHER_MAX>,
+    i64,
+    u64,
+    SaturatingI64<MIN, MAX>
+);
+impl_signed!(BoundedSaturatingI64<MIN, MAX>, i64);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingI128<const MIN: i128, const MAX: i128>(i128);
+impl_all!(
+    BoundedSaturatingI128<MIN, MAX>,
+    BoundedSaturatingI128<OTHER_MIN, OTHER_MAX>,
+    i128,
+    u128,
+    SaturatingI128<MIN, MAX>
+);
+impl_signed!(BoundedSaturatingI128<MIN, MAX>, i128);
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedSaturatingISize<const MIN: isize, const MAX: isize>(isize);
+impl_all!(
+    BoundedSaturatingISize<MIN, MAX>,
+    BoundedSaturatingISize<OTHER_MIN, OTHER_MAX>,
+    isize,
+    usize,
+    SaturatingISize<MIN, MAX>
+);
+impl_signed!(BoundedSaturatingISize<MIN, MAX>, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn construction_is_checked() {
+        use BoundsError::*;
+
+        type Foo = BoundedSaturatingU32<2, 8>;
+        assert_eq!(Foo::new(1), Err(TooSmall));
+        assert_eq!(Foo::new(2).unwrap(), 2);
+        assert_eq!(Foo::new(8).unwrap(), 8);
+        assert_eq!(Foo::new(9), Err(TooLarge));
+        assert!("100".parse::<Foo>().is_err());
+        assert_eq!(Foo::default(), 2);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn arithmetic_saturates() {
+        let a = BoundedSaturatingU32::<2, 8>::new(5).unwrap();
+        let b: BoundedSaturatingU32<2, 8> = a + 10;
+        assert_eq!(b, 8);
+        assert!(b.is_at_max());
+        assert_eq!(a - 10, 2);
+        assert_eq!(a * 3, 8);
+        assert_eq!(a / 5, 2);
+        assert_eq!(a - 1, 4);
+
+        let other = BoundedSaturatingU32::<0, 100>::new(50).unwrap();
+        assert_eq!(a + other, 8);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn assign_stays_pinned() {
+        let mut a = BoundedSaturatingI8::<-10, 10>::new(5).unwrap();
+        a += 100;
+        assert_eq!(a, 10);
+        a += 1;
+        assert_eq!(a, 10);
+        a -= 100;
+        assert_eq!(a, -10);
+        a *= 120;
+        assert_eq!(a, -10);
+        a /= -1;
+        assert_eq!(a, 10);
+        assert_eq!(-a, -10);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn inner_overflow_saturates() {
+        let mut a = BoundedSaturatingU8::<0, 255>::new(250).unwrap();
+        a += 100;
+        assert_eq!(a, 255);
+
+        let b = BoundedSaturatingI8::<-128, 127>::new(-128).unwrap();
+        assert_eq!(-b, 127);
+    }
+
+    #[test]
+    fn converts_to_and_from_saturating() {
+        let a = BoundedSaturatingU32::<2, 8>::from(SaturatingU32::from(100));
+        assert_eq!(a, 8);
+        assert_eq!(a.into_saturating(), 8);
+    }
+}
@@ -0,0 +1,133 @@
+use core::ops::{Range, RangeInclusive, Sub};
+
+use crate::bounded::BoundsError;
+
+/// A validated pair of bounds, where `min` is always less than `max`.
+///
+/// This is what the generic clamped types store internally, and a single
+/// `Bounds` can be reused to construct any number of values:
+///
+/// ```rust
+/// use clamps::wrapping::Wrapping;
+/// use clamps::Bounds;
+///
+/// let bounds = Bounds::new(0, 10);
+/// assert_eq!(Wrapping::with_bounds(12, bounds), 2);
+/// assert_eq!(Wrapping::with_bounds(-1, bounds), 9);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds<T> {
+    pub(crate) min: T,
+    pub(crate) max: T,
+}
+
+impl<T: PartialOrd> Bounds<T> {
+    /// Panics if `min` is not less than `max`. See [`Bounds::try_new`] for a
+    /// version that returns [`BoundsError::InvalidBounds`] instead.
+    pub fn new(min: T, max: T) -> Self {
+        match Self::try_new(min, max) {
+            Ok(bounds) => bounds,
+            Err(_) => panic!("MIN value must be less than MAX"),
+        }
+    }
+
+    pub fn try_new(min: T, max: T) -> Result<Self, BoundsError> {
+        if min >= max {
+            return Err(BoundsError::InvalidBounds);
+        }
+
+        Ok(Self { min, max })
+    }
+
+    pub fn min(&self) -> &T { &self.min }
+    pub fn max(&self) -> &T { &self.max }
+    pub fn into_inner(self) -> (T, T) { (self.min, self.max) }
+
+    /// Whether `value` is in `min..max`. Note that `max` is excluded, as it is
+    /// for wrapping and bounded types.
+    pub fn contains(&self, value: &T) -> bool {
+        *value >= self.min && *value < self.max
+    }
+
+    /// Whether `value` is in `min..=max`, as it is for saturating and
+    /// reflecting types.
+    pub fn contains_inclusive(&self, value: &T) -> bool {
+        *value >= self.min && *value <= self.max
+    }
+
+    pub fn range(&self) -> Range<&T> { &self.min..&self.max }
+    pub fn range_inclusive(&self) -> RangeInclusive<&T> {
+        &self.min..=&self.max
+    }
+}
+
+impl<T: PartialOrd + Clone + Sub<Output = T>> Bounds<T> {
+    /// The distance between `min` and `max`.
+    pub fn width(&self) -> T { self.max.clone() - self.min.clone() }
+}
+
+/// Supplies a pair of bounds from a marker type, so that types like
+/// [`ConstWrapping`](crate::wrapping::ConstWrapping) don't need to store them
+/// alongside each value.
+///
+/// Unlike [`FloatBounds`](crate::float::FloatBounds), the bounds are returned
+/// from functions rather than constants, so `T` doesn't need to be
+/// constructible in a `const` context.
+pub trait BoundsProvider<T> {
+    fn min() -> T;
+    fn max() -> T;
+}
+
+// The primitive integers, which (unlike floats) have a next value after each
+// one. This lets the generic types step between exclusive and inclusive
+// bounds. It's public so that it can be used as a bound, but as this module
+// is private, it can't be named or implemented outside of the crate.
+pub trait Integer {
+    const ONE: Self;
+}
+
+macro_rules! impl_integer {
+    ($($inner:ty),*) => {$(
+        impl Integer for $inner {
+            const ONE: Self = 1;
+        }
+    )*};
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_new() {
+        assert!(Bounds::try_new(0, 10).is_ok());
+        assert_eq!(Bounds::try_new(10, 10), Err(BoundsError::InvalidBounds));
+        assert_eq!(Bounds::try_new(11, 10), Err(BoundsError::InvalidBounds));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_panics() { Bounds::new(10.0, -10.0); }
+
+    #[test]
+    fn test_contains() {
+        let bounds = Bounds::new(-5, 10);
+        assert!(bounds.contains(&-5));
+        assert!(bounds.contains(&9));
+        assert!(!bounds.contains(&10));
+        assert!(!bounds.contains(&-6));
+
+        assert!(bounds.contains_inclusive(&-5));
+        assert!(bounds.contains_inclusive(&10));
+        assert!(!bounds.contains_inclusive(&11));
+    }
+
+    #[test]
+    fn test_width() {
+        assert_eq!(Bounds::new(-5, 10).width(), 15);
+        assert_eq!(Bounds::new(3u8, 4).width(), 1);
+        assert_eq!(Bounds::new(0.5, 2.0).width(), 1.5);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::bounded::BoundsError;
+use crate::bounds::Bounds;
+
+/// A builder for the generic clamped types, created by their `builder()`
+/// associated functions.
+///
+/// This names each argument explicitly, and reports invalid bounds as an error
+/// rather than panicking:
+///
+/// ```rust
+/// use clamps::bounded::BoundsError;
+/// use clamps::wrapping::Wrapping;
+///
+/// let foo = Wrapping::builder().min(0).max(10).value(12).build();
+/// assert_eq!(foo.unwrap(), 2);
+///
+/// let swapped = Wrapping::builder().min(10).max(0).value(12).build();
+/// assert_eq!(swapped, Err(BoundsError::InvalidBounds));
+/// ```
+///
+/// If no value is given, it defaults to `min`. Leaving out either bound is
+/// also reported as [`BoundsError::InvalidBounds`].
+pub struct Builder<T, C> {
+    value: Option<T>,
+    min:   Option<T>,
+    max:   Option<T>,
+    build: fn(T, Bounds<T>) -> Result<C, BoundsError>,
+}
+
+impl<T, C> Builder<T, C> {
+    // `build` constructs the clamped type once the bounds have been checked.
+    pub(crate) fn new(
+        build: fn(T, Bounds<T>) -> Result<C, BoundsError>,
+    ) -> Self {
+        Self { value: None, min: None, max: None, build }
+    }
+
+    pub fn value(mut self, value: T) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl<T: PartialOrd + Clone, C> Builder<T, C> {
+    pub fn build(self) -> Result<C, BoundsError> {
+        let (min, max) = match (self.min, self.max) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return Err(BoundsError::InvalidBounds),
+        };
+
+        let bounds = Bounds::try_new(min, max)?;
+        let value = self.value.unwrap_or_else(|| bounds.min.clone());
+        (self.build)(value, bounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bounded::{Bounded, BoundsError};
+    use crate::reflecting::Reflecting;
+    use crate::saturating::Saturating;
+    use crate::wrapping::Wrapping;
+
+    #[test]
+    fn test_build() {
+        let a = Wrapping::builder().min(0).max(10).value(12).build();
+        assert_eq!(a.unwrap(), 2);
+        let b = Saturating::builder().min(0).max(10).value(12).build();
+        assert_eq!(b.unwrap(), 10);
+        let c = Reflecting::builder().min(0).max(10).value(12).build();
+        assert_eq!(c.unwrap(), 8);
+        let d = Bounded::builder().min(0).max(10).value(7).build();
+        assert_eq!(d.unwrap(), 7);
+
+        // the order of calls doesn't matter
+        let e = Saturating::builder().value(-3.0).max(1.0).min(-1.0).build();
+        assert_eq!(e.unwrap(), -1.0);
+    }
+
+    #[test]
+    fn test_invalid_bounds() {
+        use BoundsError::*;
+
+        let a = Wrapping::builder().min(10).max(0).value(5).build();
+        assert_eq!(a, Err(InvalidBounds));
+        let b = Saturating::builder().min(5).max(5).value(5).build();
+        assert_eq!(b, Err(InvalidBounds));
+        let c = Bounded::builder().min(10).max(0).value(5).build();
+        assert_eq!(c, Err(InvalidBounds));
+        let d = Bounded::builder().min(0).max(10).value(10).build();
+        assert_eq!(d, Err(TooLarge));
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let a = Wrapping::builder().min(3).max(10).build();
+        assert_eq!(a.unwrap(), 3);
+        let b = Saturating::builder().min(3).value(5).build();
+        assert_eq!(b, Err(BoundsError::InvalidBounds));
+        let c = Bounded::<i32>::builder().build();
+        assert_eq!(c, Err(BoundsError::InvalidBounds));
+    }
+}
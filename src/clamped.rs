@@ -0,0 +1,59 @@
+/// A common interface for all clamped types, concrete or generic.
+///
+/// This allows code to be generic over the different forms of clamping:
+///
+/// ```rust
+/// use clamps::saturating::SaturatingU32;
+/// use clamps::Clamped;
+///
+/// fn is_halfway<C: Clamped<Inner = u32>>(c: &C) -> bool {
+///     c.inner() - c.min_bound() == (c.max_bound() - c.min_bound()) / 2
+/// }
+///
+/// assert!(is_halfway(&SaturatingU32::<0, 10>::from(5)));
+/// ```
+pub trait Clamped {
+    type Inner;
+
+    /// The result of [`Clamped::clamp`]. This is `Self` for types that can
+    /// always be constructed, or a `Result` for the bounded types.
+    type Output;
+
+    fn inner(&self) -> Self::Inner;
+    fn min_bound(&self) -> Self::Inner;
+    fn max_bound(&self) -> Self::Inner;
+
+    /// Clamps `value` into the same bounds as `self`.
+    ///
+    /// The concrete integer types also implement [`Ord`], which has its own
+    /// `clamp()` method. Outside of generic code, call this as
+    /// `Clamped::clamp(&foo, value)` to avoid the ambiguity.
+    fn clamp(&self, value: Self::Inner) -> Self::Output;
+}
+
+/// A single clamping operation on `T`, for when the kind of clamping is only
+/// known at runtime. Unlike [`Clamped`], the result is always a plain `T`, so
+/// this can be used as a trait object:
+///
+/// ```rust
+/// use clamps::bounded::Bounded;
+/// use clamps::saturating::Saturating;
+/// use clamps::wrapping::Wrapping;
+/// use clamps::Clamp;
+///
+/// let pipeline: Vec<Box<dyn Clamp<i32>>> = vec![
+///     Box::new(Wrapping::new(0, 0, 360)),
+///     Box::new(Saturating::new(0, 0, 180)),
+///     Box::new(Bounded::new(90, 0, 100).unwrap()),
+/// ];
+///
+/// let apply = |value| pipeline.iter().fold(value, |v, c| c.clamp(v));
+/// assert_eq!(apply(400), 40);
+/// assert_eq!(apply(-90), 99);
+/// ```
+///
+/// This is implemented by the generic types. As they also implement
+/// [`Clamped`], avoid importing both traits into the same scope.
+pub trait Clamp<T> {
+    fn clamp(&self, value: T) -> T;
+}
@@ -0,0 +1,65 @@
+//! Checks that invalid bounds on the concrete types are rejected at compile
+//! time. These are only built as doctests, as `compile_fail` isn't available
+//! to regular tests.
+
+/// ```compile_fail,E0080
+/// let _ = clamps::wrapping::WrappingU32::<10, 10>::from(5);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = clamps::wrapping::WrappingI8::<5, -5>::default();
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = clamps::wrapping::WrappingChar::<'\u{D000}', '\u{F000}'>::new('a');
+/// ```
+///
+/// The generic type's inclusive bounds only make sense for integers:
+///
+/// ```compile_fail,E0277
+/// let _ = clamps::wrapping::Wrapping::new_inclusive(0.5, 0.0, 1.0);
+/// ```
+pub struct Wrapping;
+
+/// ```compile_fail,E0080
+/// let _ = clamps::saturating::SaturatingUSize::<15, 10>::from(5);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = clamps::saturating::SaturatingU8::<3, 3>::all();
+/// ```
+pub struct Saturating;
+
+/// ```compile_fail,E0080
+/// let _ = clamps::reflecting::ReflectingU32::<10, 5>::from(3);
+/// ```
+pub struct Reflecting;
+
+/// ```compile_fail,E0080
+/// let _ = clamps::bounded::BoundedUSize::<10, 10>::try_from(5);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = clamps::bounded::BoundedInclusiveU16::<15, 10>::try_from(5);
+/// ```
+///
+/// The check also happens in `const` contexts:
+///
+/// ```compile_fail,E0080
+/// use clamps::bounded::{BoundedU32, BoundsError};
+/// const FOO: Result<BoundedU32<8, 2>, BoundsError> =
+///     BoundedU32::try_new_const(5);
+/// let _ = FOO;
+/// ```
+pub struct Bounded;
+
+/// `MIN` must also be greater than zero:
+///
+/// ```compile_fail,E0080
+/// let _ = clamps::bounded::NonZeroBoundedU32::<0, 100>::try_from(5);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = clamps::bounded::NonZeroBoundedU32::<10, 5>::try_from(5);
+/// ```
+pub struct NonZeroBounded;
@@ -0,0 +1,203 @@
+// Conversions and comparisons between the concrete wrapping, saturating, and
+// bounded types.
+//
+// Comparisons only look at the inner values, so they work regardless of the
+// bounds on either side. Conversions are only provided between types that
+// share an inner type and bounds. Converting into a wrapping or saturating
+// type applies its usual clamping, while converting into a bounded type can
+// fail.
+//
+// Note that `MAX` is only in range for saturating types. This means that a
+// saturating value sitting on `MAX` will wrap around to `MIN` when converted
+// into a wrapping type, and can't be converted into a bounded type at all.
+
+use core::cmp::Ordering;
+
+use crate::bounded::*;
+use crate::saturating::*;
+use crate::wrapping::*;
+
+macro_rules! impl_from {
+    ($from:ident, $to:ident, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> From<$from<MIN, MAX>>
+            for $to<MIN, MAX>
+        {
+            fn from(value: $from<MIN, MAX>) -> Self {
+                Self::from(value.inner())
+            }
+        }
+    };
+}
+
+macro_rules! impl_try_from {
+    ($from:ident, $to:ident, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> TryFrom<$from<MIN, MAX>>
+            for $to<MIN, MAX>
+        {
+            type Error = BoundsError;
+            fn try_from(value: $from<MIN, MAX>) -> Result<Self, Self::Error> {
+                Self::try_from(value.inner())
+            }
+        }
+    };
+}
+
+macro_rules! impl_cmp {
+    ($a:ident, $b:ident, $inner:ty) => {
+        impl<
+                const MIN: $inner,
+                const MAX: $inner,
+                const OTHER_MIN: $inner,
+                const OTHER_MAX: $inner,
+            > PartialEq<$b<OTHER_MIN, OTHER_MAX>> for $a<MIN, MAX>
+        {
+            fn eq(&self, other: &$b<OTHER_MIN, OTHER_MAX>) -> bool {
+                self.inner() == other.inner()
+            }
+        }
+
+        impl<
+                const MIN: $inner,
+                const MAX: $inner,
+                const OTHER_MIN: $inner,
+                const OTHER_MAX: $inner,
+            > PartialOrd<$b<OTHER_MIN, OTHER_MAX>> for $a<MIN, MAX>
+        {
+            fn partial_cmp(
+                &self, other: &$b<OTHER_MIN, OTHER_MAX>,
+            ) -> Option<Ordering> {
+                self.inner().partial_cmp(&other.inner())
+            }
+        }
+    };
+}
+
+macro_rules! impl_convert {
+    ($bounded:ident, $saturating:ident, $wrapping:ident, $inner:ty) => {
+        impl_from!($bounded, $saturating, $inner);
+        impl_from!($bounded, $wrapping, $inner);
+        impl_from!($wrapping, $saturating, $inner);
+        impl_from!($saturating, $wrapping, $inner);
+        impl_try_from!($saturating, $bounded, $inner);
+        impl_try_from!($wrapping, $bounded, $inner);
+
+        impl_cmp!($bounded, $saturating, $inner);
+        impl_cmp!($bounded, $wrapping, $inner);
+        impl_cmp!($saturating, $bounded, $inner);
+        impl_cmp!($saturating, $wrapping, $inner);
+        impl_cmp!($wrapping, $bounded, $inner);
+        impl_cmp!($wrapping, $saturating, $inner);
+
+        impl<const MIN: $inner, const MAX: $inner> $bounded<MIN, MAX> {
+            pub fn into_saturating(self) -> $saturating<MIN, MAX> {
+                self.into()
+            }
+            pub fn into_wrapping(self) -> $wrapping<MIN, MAX> { self.into() }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> $saturating<MIN, MAX> {
+            pub fn into_bounded(
+                self,
+            ) -> Result<$bounded<MIN, MAX>, BoundsError> {
+                self.try_into()
+            }
+            pub fn into_wrapping(self) -> $wrapping<MIN, MAX> { self.into() }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> $wrapping<MIN, MAX> {
+            pub fn into_bounded(
+                self,
+            ) -> Result<$bounded<MIN, MAX>, BoundsError> {
+                self.try_into()
+            }
+            pub fn into_saturating(self) -> $saturating<MIN, MAX> {
+                self.into()
+            }
+        }
+    };
+}
+
+impl_convert!(BoundedU8, SaturatingU8, WrappingU8, u8);
+impl_convert!(BoundedU16, SaturatingU16, WrappingU16, u16);
+impl_convert!(BoundedU32, SaturatingU32, WrappingU32, u32);
+impl_convert!(BoundedU64, SaturatingU64, WrappingU64, u64);
+impl_convert!(BoundedU128, SaturatingU128, WrappingU128, u128);
+impl_convert!(BoundedUSize, SaturatingUSize, WrappingUSize, usize);
+impl_convert!(BoundedI8, SaturatingI8, WrappingI8, i8);
+impl_convert!(BoundedI16, SaturatingI16, WrappingI16, i16);
+impl_convert!(BoundedI32, SaturatingI32, WrappingI32, i32);
+impl_convert!(BoundedI64, SaturatingI64, WrappingI64, i64);
+impl_convert!(BoundedI128, SaturatingI128, WrappingI128, i128);
+impl_convert!(BoundedISize, SaturatingISize, WrappingISize, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounded_into_others() {
+        let a = BoundedU32::<2, 8>::try_from(5).unwrap();
+        assert_eq!(SaturatingU32::<2, 8>::from(a), 5);
+        assert_eq!(WrappingU32::<2, 8>::from(a), 5);
+        assert_eq!(a.into_saturating(), 5);
+        assert_eq!(a.into_wrapping(), 5);
+
+        // the largest bounded value is always in range for the others
+        let b = BoundedI32::<-2, 8>::try_from(7).unwrap();
+        assert_eq!(b.into_saturating(), 7);
+        assert_eq!(b.into_wrapping(), 7);
+    }
+
+    #[test]
+    fn wrapping_into_others() {
+        let a = WrappingU32::<2, 8>::from(7);
+        assert_eq!(SaturatingU32::<2, 8>::from(a), 7);
+        assert_eq!(BoundedU32::<2, 8>::try_from(a).unwrap(), 7);
+        assert_eq!(a.into_saturating(), 7);
+        assert_eq!(a.into_bounded().unwrap(), 7);
+    }
+
+    #[test]
+    fn saturating_into_others() {
+        let a = SaturatingU32::<2, 8>::from(7);
+        assert_eq!(WrappingU32::<2, 8>::from(a), 7);
+        assert_eq!(BoundedU32::<2, 8>::try_from(a).unwrap(), 7);
+        assert_eq!(a.into_wrapping(), 7);
+        assert_eq!(a.into_bounded().unwrap(), 7);
+    }
+
+    #[test]
+    fn saturating_max_is_out_of_range() {
+        let a = SaturatingU32::<2, 8>::from(100);
+        assert!(a.is_at_max());
+        assert_eq!(a.into_wrapping(), 2);
+        assert_eq!(a.into_bounded(), Err(BoundsError::TooLarge));
+
+        let b = SaturatingI8::<-128, 127>::from(127);
+        assert_eq!(b.into_wrapping(), -128);
+        assert_eq!(BoundedI8::try_from(b), Err(BoundsError::TooLarge));
+    }
+
+    #[test]
+    fn compare_across_families() {
+        let a = WrappingU32::<0, 8>::from(5);
+        let b = SaturatingU32::<0, 8>::from(5);
+        let c = BoundedU32::<0, 8>::try_from(5).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(b, a);
+        assert_eq!(a, c);
+        assert_eq!(c, a);
+        assert_eq!(b, c);
+        assert_eq!(c, b);
+
+        // bounds don't need to match
+        let d = SaturatingU32::<6, 100>::from(0);
+        assert_ne!(a, d);
+        assert_ne!(d, a);
+        assert!(a < d);
+        assert!(d > a);
+        assert!(c <= d);
+        assert!(d >= c);
+        assert_eq!(b.partial_cmp(&d), Some(Ordering::Less));
+    }
+}
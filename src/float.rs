@@ -0,0 +1,23 @@
+//! Bounds for the concrete floating-point types.
+
+/// Floats can't be used as const generic parameters, so the concrete float
+/// types (e.g. [`SaturatingF32`](crate::saturating::SaturatingF32)) take their
+/// bounds from a marker type implementing this trait instead.
+///
+/// ```rust
+/// use clamps::float::FloatBounds;
+/// use clamps::saturating::SaturatingF32;
+///
+/// struct Unit;
+/// impl FloatBounds<f32> for Unit {
+///     const MIN: f32 = 0.0;
+///     const MAX: f32 = 1.0;
+/// }
+///
+/// let foo = SaturatingF32::<Unit>::from(1.5);
+/// assert_eq!(foo, 1.0);
+/// ```
+pub trait FloatBounds<T> {
+    const MIN: T;
+    const MAX: T;
+}
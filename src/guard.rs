@@ -0,0 +1,67 @@
+//! Guards for modifying the inner value of the concrete types directly.
+
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+/// A mutable view of a concrete type's inner value, created by its
+/// `inner_mut()` method.
+///
+/// The value can be modified freely (and may leave the range while doing so),
+/// as the usual clamping logic is only applied once the guard is dropped:
+///
+/// ```rust
+/// use clamps::wrapping::WrappingU8;
+///
+/// let mut cursor = WrappingU8::<0, 16>::from(10);
+/// {
+///     let mut inner = cursor.inner_mut();
+///     *inner *= 3;
+///     *inner += 4;
+/// }
+/// assert_eq!(cursor, 2);
+/// ```
+pub struct ClampGuard<'a, T, I: Copy> {
+    target: &'a mut T,
+    value:  I,
+    commit: fn(&mut T, I),
+}
+
+impl<'a, T, I: Copy> ClampGuard<'a, T, I> {
+    // `commit` is responsible for clamping `value` back into `target`.
+    pub(crate) fn new(
+        target: &'a mut T, value: I, commit: fn(&mut T, I),
+    ) -> Self {
+        Self { target, value, commit }
+    }
+}
+
+impl<T, I: Copy> Deref for ClampGuard<'_, T, I> {
+    type Target = I;
+    fn deref(&self) -> &I { &self.value }
+}
+
+impl<T, I: Copy> DerefMut for ClampGuard<'_, T, I> {
+    fn deref_mut(&mut self) -> &mut I { &mut self.value }
+}
+
+impl<T, I: Copy> Drop for ClampGuard<'_, T, I> {
+    fn drop(&mut self) { (self.commit)(self.target, self.value) }
+}
+
+impl<T, I: Copy + fmt::Debug> fmt::Debug for ClampGuard<'_, T, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ClampGuard {{value: {:?}}}", self.value)
+    }
+}
+
+/// What a bounded type should do when a [`ClampGuard`] is dropped while
+/// holding an out-of-range value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutOfBounds {
+    /// Discard the changes, keeping the value from before the guard was
+    /// created.
+    Reset,
+    /// Panic. Note that as this happens inside of `drop()`, panicking while
+    /// already unwinding from another panic will abort the process.
+    Panic,
+}
@@ -0,0 +1,187 @@
+//! Iterators over the values of the concrete types.
+
+use core::iter::FusedIterator;
+
+use crate::bounded::{BoundedU32, BoundsError};
+use crate::saturating::SaturatingU32;
+use crate::wrapping::WrappingU32;
+
+/// An iterator over every in-range value of a concrete type, in ascending
+/// order. Created by the `all()` associated function on each type.
+///
+/// Values are tracked as offsets from `MIN`. The iterator knows its exact
+/// length, but as with any [`ExactSizeIterator`], calling `len()` will panic if
+/// that length can't be represented as a `usize`.
+pub struct All<T> {
+    front: u128,
+    back:  u128,
+    done:  bool,
+    build: fn(u128) -> T,
+}
+
+impl<T> All<T> {
+    // `back` is the offset of the last value, which is included.
+    pub(crate) fn new(back: u128, build: fn(u128) -> T) -> Self {
+        Self { front: 0, back, done: false, build }
+    }
+}
+
+impl<T> Iterator for All<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let item = (self.build)(self.front);
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            self.front += 1;
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let len = (self.back - self.front).checked_add(1);
+        match len.and_then(|len| usize::try_from(len).ok()) {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for All<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let item = (self.build)(self.back);
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            self.back -= 1;
+        }
+        Some(item)
+    }
+}
+
+impl<T> ExactSizeIterator for All<T> {}
+impl<T> FusedIterator for All<T> {}
+
+/// An iterator that steps from one value towards another, stopping before it
+/// reaches the end. Created by the `step_by()` associated function on each
+/// type.
+pub struct Steps<T> {
+    start: T,
+    next:  u128,
+    end:   u128,
+    step:  u128,
+    build: fn(&T, u128) -> T,
+}
+
+impl<T> Steps<T> {
+    // `end` is the distance from `start` to the (excluded) end value, and
+    // `build` is given `start` and the distance travelled so far.
+    pub(crate) fn new(
+        start: T, end: u128, step: usize, build: fn(&T, u128) -> T,
+    ) -> Self {
+        assert!(step != 0, "step must be greater than zero");
+        Self { start, next: 0, end, step: step as u128, build }
+    }
+}
+
+impl<T> Iterator for Steps<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+
+        let item = (self.build)(&self.start, self.next);
+        self.next = self.next.checked_add(self.step).unwrap_or(self.end);
+        Some(item)
+    }
+}
+
+impl<T> FusedIterator for Steps<T> {}
+
+/// Adapters that clamp each item of an iterator into a concrete type.
+///
+/// ```rust
+/// use clamps::iter::ClampExt;
+/// use clamps::saturating::SaturatingU32;
+///
+/// let levels: Vec<SaturatingU32<0, 100>> =
+///     [20, 150, 80].into_iter().clamp_saturating().collect();
+/// assert_eq!(levels, [20, 100, 80]);
+/// ```
+pub trait ClampExt: Iterator<Item = u32> + Sized {
+    fn clamp_saturating<const MIN: u32, const MAX: u32>(
+        self,
+    ) -> impl Iterator<Item = SaturatingU32<MIN, MAX>> {
+        self.map(SaturatingU32::from)
+    }
+
+    fn clamp_wrapping<const MIN: u32, const MAX: u32>(
+        self,
+    ) -> impl Iterator<Item = WrappingU32<MIN, MAX>> {
+        self.map(WrappingU32::from)
+    }
+
+    /// Yields an error for each item that's out of range, rather than
+    /// stopping.
+    fn clamp_bounded<const MIN: u32, const MAX: u32>(
+        self,
+    ) -> impl Iterator<Item = Result<BoundedU32<MIN, MAX>, BoundsError>> {
+        self.map(BoundedU32::try_from)
+    }
+}
+
+impl<I: Iterator<Item = u32>> ClampExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_saturating() {
+        let values = [0, 5, 7, 10, 11];
+        let clamped: Vec<SaturatingU32<5, 10>> =
+            values.into_iter().clamp_saturating().collect();
+        assert_eq!(clamped, [5, 5, 7, 10, 10]);
+    }
+
+    #[test]
+    fn test_clamp_wrapping() {
+        let values = [0, 5, 7, 10, 11];
+        let clamped: Vec<_> =
+            values.into_iter().clamp_wrapping::<5, 10>().collect();
+        assert_eq!(clamped, [5, 5, 7, 5, 6]);
+    }
+
+    #[test]
+    fn test_clamp_bounded() {
+        use BoundsError::*;
+
+        let values = [0, 5, 7, 10];
+        let clamped: Vec<_> =
+            values.into_iter().clamp_bounded::<5, 10>().collect();
+        assert_eq!(clamped[0], Err(TooSmall));
+        assert_eq!(clamped[1].as_ref().unwrap(), &5);
+        assert_eq!(clamped[2].as_ref().unwrap(), &7);
+        assert_eq!(clamped[3], Err(TooLarge));
+
+        // collecting into a Result stops at the first error
+        let all: Result<Vec<BoundedU32<5, 10>>, _> =
+            values.into_iter().clamp_bounded().collect();
+        assert_eq!(all, Err(TooSmall));
+    }
+}
@@ -19,21 +19,25 @@
 //!
 //! # What this crate does
 //!
-//! Clamps provides three main forms of clamping:
+//! Clamps provides four main forms of clamping:
 //! - Wrapping types (will wrap to fit into range)
 //! - Saturating types (will saturate to fit into range)
+//! - Reflecting types (will bounce off of the bounds to fit into range)
 //! - Bounded types (can't be constructed at all when out of range)
+//!   - `BoundedInclusive` types are also available, where `MAX` is in range.
 //!
 //! These in turn have two variants:
-//! - The generic `Wrapping<T>`, `Saturating<T>`, and `Bounded<T>` types:
+//! - The generic `Wrapping<T>`, `Saturating<T>`, `Reflecting<T>`, and
+//!   `Bounded<T>` types:
 //!   - Can be used with any compatible type.
 //!   - Can be assigned bounds at runtime.
 //!   - More expensive as the bounds have to be contained within the type
-//!   itself.
+//!     itself.
 //! - Concrete types such as `WrappingUSize<MIN, MAX>`:
 //!   - Cheaper and more ergonimic.
 //!   - Bounds are `const` and must be set at compile time.
-//!   - Only available for integers.
+//!   - Only available for integers, although `f32` and `f64` variants are
+//!     available by passing the bounds as a marker type. See [`float`].
 //!
 //! # Examples
 //!
@@ -52,6 +56,7 @@
 //! ```rust
 //! use clamps::wrapping::WrappingU32;
 //!
+//! # #[cfg(not(all(feature = "strict-debug", debug_assertions)))] {
 //! let mut wrapping = WrappingU32::<2, 8>::from(5);
 //! assert_eq!(wrapping, 5);
 //! assert_eq!(wrapping + 5, 10);
@@ -65,12 +70,14 @@
 //! wrapping -= 3;
 //! assert_ne!(wrapping, 1);
 //! assert_eq!(wrapping, 7);
+//! # }
 //! ```
 //!
 //! Saturating types:
 //! ```rust
 //! use clamps::saturating::SaturatingU32;
 //!
+//! # #[cfg(not(all(feature = "strict-debug", debug_assertions)))] {
 //! let mut saturating = SaturatingU32::<5, 10>::from(8);
 //! assert_eq!(saturating, 8);
 //! assert_eq!(saturating + 5, 13);
@@ -82,10 +89,75 @@
 //! // 10 - 100 (bounds: 5..=10) = 5
 //! saturating -= 100;
 //! assert_eq!(saturating, 5);
+//! # }
 //! ```
+//!
+//! Concrete wrapping, saturating, and bounded types with the same inner type
+//! and bounds can be converted between one another with `From`/`TryFrom`, or
+//! with `into_wrapping()`, `into_saturating()`, and `into_bounded()`. Keep in
+//! mind that `MAX` is only in range for saturating types:
+//! ```rust
+//! use clamps::bounded::BoundsError;
+//! use clamps::saturating::SaturatingU32;
+//!
+//! let saturating = SaturatingU32::<2, 8>::from(100);
+//! assert_eq!(saturating.into_wrapping(), 2);
+//! assert_eq!(saturating.into_bounded(), Err(BoundsError::TooLarge));
+//! ```
+//!
+//! The bounds of the concrete integer types are checked at compile time, so
+//! invalid bounds are a build error rather than a panic:
+//! ```compile_fail
+//! use clamps::wrapping::WrappingU32;
+//!
+//! // error: MIN must be less than MAX
+//! let wrapping = WrappingU32::<10, 10>::from(5);
+//! ```
+//!
+//! # Features
+//!
+//! - `std`: Implements `std::error::Error` for the error types. Everything else
+//!   only needs `core`, so the crate is `no_std` unless this is enabled.
+//! - `serde`: Implements `Serialize` and `Deserialize` for all types. Concrete
+//!   types are (de)serialized as their inner value, while generic types are
+//!   written as a struct of `inner`, `min`, and `max`. Clamping is always
+//!   applied when deserializing.
+//! - `num-traits`: Implements `num_traits::Bounded` for the concrete types,
+//!   giving the smallest and largest values that are actually in range.
+//! - `angles`: Adds the `wrapping::angles` module, with a `Degrees` type that
+//!   can be converted to and from radians.
+//! - `strict-debug`: In debug builds, the `wrapping_*` and `saturating_*`
+//!   methods of the concrete integer types (along with the assign operators
+//!   and `Sum`/`Product`, which use them) panic if their result had to be
+//!   wrapped or saturated. This can help find places where clamping hides a
+//!   logic error, and compiles away entirely in release builds. Constructors
+//!   and the `overflowing_*`/`*_clamped` methods are never checked.
+//! - `debug-invariants`: In debug builds, checks that the concrete integer
+//!   types are still within their bounds after every conversion and assign
+//!   operator. This is mostly useful for testing the crate itself.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 pub mod bounded;
+mod bounds;
+mod builder;
+mod clamped;
+#[cfg(doctest)]
+mod compile_fail;
+mod convert;
+pub mod float;
+pub mod guard;
+pub mod iter;
 mod macros;
+pub mod reflecting;
 pub mod saturating;
+pub mod slice;
+#[cfg(test)]
+mod test_utils;
 pub mod wrapping;
+
+pub use bounds::{Bounds, BoundsProvider};
+pub use builder::Builder;
+pub use clamped::{Clamp, Clamped};
+#[doc(hidden)]
+pub use macros::ClampRange;
@@ -1,14 +1,21 @@
+use crate::wrapping::wrap;
+
 // provides an easier way to define two implementations:
 // - impl $trait<u32> for WrappingT<MIN, MAX>
 // - impl $trait<WrappingT<OTHER_MIN, OTHER_MAX>> for WrappingT<MIN, MAX>
 //
 // The generics were getting a bit ridiculous, so this short-hand exists to
 // make defining arithmetic traits like Add, Sub, etc. much easier.
+//
+// The macros for concrete integer types go through inner() rather than .0, so
+// that types with a different representation (e.g. NonZero) can use them.
 macro_rules! impl_arith {
     ($type:ty, $other:ty, $inner:ty, $trait:ident, $fn:ident, $impl:expr) => {
         impl<const MIN: $inner, const MAX: $inner> $trait<$inner> for $type {
             type Output = $inner;
-            fn $fn(self, other: $inner) -> Self::Output { $impl(self.0, other) }
+            fn $fn(self, other: $inner) -> Self::Output {
+                $impl(self.inner(), other)
+            }
         }
 
         impl<
@@ -20,7 +27,7 @@ macro_rules! impl_arith {
         {
             type Output = $inner;
             fn $fn(self, other: $other) -> Self::Output {
-                $impl(self.0, other.0)
+                $impl(self.inner(), other.inner())
             }
         }
     };
@@ -30,7 +37,10 @@ pub(crate) use impl_arith;
 macro_rules! impl_arith_assign {
     ($type:ty, $other:ty, $inner:ty, $trait:ident, $fn:ident, $op:ident) => {
         impl<const MIN: $inner, const MAX: $inner> $trait<$inner> for $type {
-            fn $fn(&mut self, other: $inner) { *self = self.$op(other).into() }
+            fn $fn(&mut self, other: $inner) {
+                *self = self.$op(other).into();
+                invariant_debug_assert!(self);
+            }
         }
 
         impl<
@@ -40,16 +50,51 @@ macro_rules! impl_arith_assign {
                 const OTHER_MAX: $inner,
             > $trait<$other> for $type
         {
-            fn $fn(&mut self, other: $other) { *self = self.$op(other).into() }
+            fn $fn(&mut self, other: $other) {
+                *self = self.$op(other).into();
+                invariant_debug_assert!(self);
+            }
         }
     };
 }
 pub(crate) use impl_arith_assign;
 
+// Like impl_arith_assign, but for types that provide their own clamping
+// arithmetic (e.g. saturating_add) that the assign operators should use.
+macro_rules! impl_method_assign {
+    (
+        $type:ty, $other:ty, $inner:ty, $trait:ident, $fn:ident, $method:ident
+    ) => {
+        impl<const MIN: $inner, const MAX: $inner> $trait<$inner> for $type {
+            fn $fn(&mut self, other: $inner) {
+                *self = self.$method(other);
+                invariant_debug_assert!(self);
+            }
+        }
+
+        impl<
+                const MIN: $inner,
+                const MAX: $inner,
+                const OTHER_MIN: $inner,
+                const OTHER_MAX: $inner,
+            > $trait<$other> for $type
+        {
+            fn $fn(&mut self, other: $other) {
+                *self = self.$method(other.inner());
+                invariant_debug_assert!(self);
+            }
+        }
+    };
+}
+pub(crate) use impl_method_assign;
+
+// Values are ordered and compared by their inner value alone, so values with
+// different bounds but the same inner value are equal. Ord is only defined for
+// matching bounds, but always agrees with the cross-bounds PartialOrd.
 macro_rules! impl_ord {
     ($type:ty, $other:ty, $inner:ty) => {
         impl<const MIN: $inner, const MAX: $inner> PartialEq<$inner> for $type {
-            fn eq(&self, other: &$inner) -> bool { self.0 == *other }
+            fn eq(&self, other: &$inner) -> bool { self.inner() == *other }
         }
 
         impl<
@@ -59,7 +104,9 @@ macro_rules! impl_ord {
                 const OTHER_MAX: $inner,
             > PartialEq<$other> for $type
         {
-            fn eq(&self, other: &$other) -> bool { self.0 == other.0 }
+            fn eq(&self, other: &$other) -> bool {
+                self.inner() == other.inner()
+            }
         }
         impl<const MIN: $inner, const MAX: $inner> Eq for $type {}
 
@@ -67,7 +114,7 @@ macro_rules! impl_ord {
             for $type
         {
             fn partial_cmp(&self, other: &$inner) -> Option<Ordering> {
-                self.0.partial_cmp(other)
+                self.inner().partial_cmp(other)
             }
         }
 
@@ -79,12 +126,837 @@ macro_rules! impl_ord {
             > PartialOrd<$other> for $type
         {
             fn partial_cmp(&self, other: &$other) -> Option<Ordering> {
-                self.0.partial_cmp(&other.0)
+                self.inner().partial_cmp(&other.inner())
             }
         }
         impl<const MIN: $inner, const MAX: $inner> Ord for $type {
-            fn cmp(&self, other: &Self) -> Ordering { self.0.cmp(&other.0) }
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.inner().cmp(&other.inner())
+            }
         }
     };
 }
 pub(crate) use impl_ord;
+
+// With the strict-debug feature, debug builds panic if an operation's result
+// had to be wrapped or saturated to fit in range. $result is the unclamped
+// result as an Option, where None means the inner type overflowed, and $range
+// is the separator between the bounds in the message (".." or "..=").
+macro_rules! strict_debug_assert {
+    (
+        $result:expr, $verb:literal, $lhs:expr, $op:literal, $rhs:expr,
+        $range:literal
+    ) => {
+        #[cfg(feature = "strict-debug")]
+        debug_assert!(
+            $result.is_some_and(Self::contains),
+            concat!(
+                "{} ", $op, " {} was ", $verb, " to fit in {}", $range, "{}"
+            ),
+            $lhs,
+            $rhs,
+            MIN,
+            MAX
+        );
+    };
+}
+pub(crate) use strict_debug_assert;
+
+// With the debug-invariants feature, checks that a freshly constructed value
+// actually lies within its bounds. Unlike strict_debug_assert, this should
+// never fire, so a panic here is always a bug in this crate.
+macro_rules! invariant_debug_assert {
+    ($value:expr) => {
+        #[cfg(feature = "debug-invariants")]
+        debug_assert!(
+            Self::contains($value.0),
+            "inner value {} escaped its bounds (MIN = {}, MAX = {})",
+            $value.0,
+            MIN,
+            MAX
+        );
+    };
+}
+pub(crate) use invariant_debug_assert;
+
+// Equality only considers the inner value, so hashing has to do the same.
+macro_rules! impl_hash {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> Hash for $type {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.inner().hash(state)
+            }
+        }
+    };
+}
+pub(crate) use impl_hash;
+
+// The bounds as associated consts, so they can be read from the type alone.
+macro_rules! impl_bound_consts {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// The same as `min_bound()`, but without needing a value.
+            pub const MIN_BOUND: $inner = MIN;
+            /// The same as `max_bound()`, but without needing a value.
+            pub const MAX_BOUND: $inner = MAX;
+        }
+    };
+}
+pub(crate) use impl_bound_consts;
+
+macro_rules! impl_as_float {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// The inner value as an `f64`. This is exact for inner types of
+            /// up to 32 bits, but larger values may be rounded.
+            pub fn as_f64(&self) -> f64 { self.inner() as f64 }
+
+            /// The inner value as an `f32`. This is exact for inner types of
+            /// up to 16 bits, but larger values may be rounded.
+            pub fn as_f32(&self) -> f32 { self.inner() as f32 }
+        }
+    };
+}
+pub(crate) use impl_as_float;
+
+// Checks the bounds at compile time. This is evaluated whenever a function
+// that refers to VALID_BOUNDS is used, so constructing something like a
+// WrappingU32<10, 10> is a build error rather than a panic.
+macro_rules! impl_valid_bounds {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            const VALID_BOUNDS: () =
+                assert!(MIN < MAX, "MIN must be less than MAX");
+        }
+    };
+}
+pub(crate) use impl_valid_bounds;
+
+// Describes the size of the range. $largest is the largest value in range, so
+// MAX - 1 for the exclusive types.
+macro_rules! impl_width {
+    ($type:ty, $inner:ty, $uinner:ty, $largest:expr) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// The distance from `MIN` to `MAX`. This is unsigned, so it can't
+            /// overflow even when the bounds are the inner type's extremes.
+            pub const fn width() -> $uinner { MAX.abs_diff(MIN) }
+
+            /// The number of distinct values in range.
+            pub const fn cardinality() -> u128 {
+                let () = Self::VALID_BOUNDS;
+                ($largest).abs_diff(MIN) as u128 + 1
+            }
+        }
+    };
+}
+pub(crate) use impl_width;
+
+macro_rules! impl_default {
+    ($type:ty, $inner:ty) => {
+        /// The default value is `MIN`, as it's the only value guaranteed to be
+        /// within bounds.
+        impl<const MIN: $inner, const MAX: $inner> Default for $type {
+            fn default() -> Self {
+                let () = Self::VALID_BOUNDS;
+                Self(MIN)
+            }
+        }
+    };
+}
+pub(crate) use impl_default;
+
+// Display shows the inner value only, passing along any formatting options.
+macro_rules! impl_display {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.inner(), f)
+            }
+        }
+    };
+}
+pub(crate) use impl_display;
+
+// Read-only access to the inner value. DerefMut is deliberately left out, as it
+// would allow the value to be changed without being clamped. Borrow is sound
+// because Eq, Ord, and Hash all only consider the inner value.
+macro_rules! impl_deref {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> Deref for $type {
+            type Target = $inner;
+            fn deref(&self) -> &$inner { &self.0 }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> AsRef<$inner> for $type {
+            fn as_ref(&self) -> &$inner { &self.0 }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> core::borrow::Borrow<$inner>
+            for $type
+        {
+            fn borrow(&self) -> &$inner { &self.0 }
+        }
+    };
+}
+pub(crate) use impl_deref;
+
+// Owned access to the inner value, for parity with the generic types. This
+// goes through inner() so that it also works for the NonZero types.
+macro_rules! impl_into_inner {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Consumes `self`, returning the inner value. As the concrete
+            /// types are `Copy`, this is equivalent to [`Self::inner`].
+            pub fn into_inner(self) -> $inner { self.inner() }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> From<$type> for $inner {
+            fn from(value: $type) -> $inner { value.into_inner() }
+        }
+    };
+}
+pub(crate) use impl_into_inner;
+
+// Exposes the clamping logic as a pure function on the inner type. $impl is
+// given the raw value, and should return $output.
+macro_rules! impl_apply {
+    ($type:ty, $inner:ty, $output:ty, $impl:expr) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Applies this type's clamping to a raw value, without keeping
+            /// the result as `Self`.
+            pub fn apply(value: $inner) -> $output { $impl(value) }
+        }
+    };
+}
+pub(crate) use impl_apply;
+
+// Transforms the inner value with a closure, then clamps the result with
+// $impl. The bounded types name this try_map, as their $output is a Result.
+macro_rules! impl_map {
+    ($type:ty, $inner:ty, $fn:ident, $output:ty, $impl:expr) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Applies `f` to the inner value, clamping the result as usual.
+            pub fn $fn(self, f: impl FnOnce($inner) -> $inner) -> $output {
+                $impl(f(self.inner()))
+            }
+        }
+    };
+}
+pub(crate) use impl_map;
+
+// Named versions of Ord::max() and Ord::min(), which are easy to mistake for
+// the inner type's methods (reached through Deref) and vice versa.
+macro_rules! impl_min_max {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Returns whichever value is larger, or `other` if they're equal.
+            pub fn max_with(self, other: Self) -> Self { Ord::max(self, other) }
+
+            /// Returns whichever value is smaller, or `self` if they're equal.
+            pub fn min_with(self, other: Self) -> Self { Ord::min(self, other) }
+        }
+    };
+}
+pub(crate) use impl_min_max;
+
+// Maps values to and from 0.0..=1.0, where 0.0 is MIN and 1.0 is MAX. Each
+// family decides how to build itself from denormalize() (as from_normalized),
+// since the top bound isn't always in range.
+macro_rules! impl_normalize {
+    ($type:ty, $other:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Returns `(inner - MIN) / (MAX - MIN)`.
+            pub fn normalize(&self) -> f64 {
+                self.inner().abs_diff(MIN) as f64 / MAX.abs_diff(MIN) as f64
+            }
+
+            // The inverse of normalize(), rounding to the nearest value. `t`
+            // is clamped to 0.0..=1.0 beforehand.
+            fn denormalize(t: f64) -> $inner {
+                let () = Self::VALID_BOUNDS;
+
+                // f64::round() isn't available without std, but the offset is
+                // never negative, so adding 0.5 and truncating does the same
+                let width = MAX.abs_diff(MIN) as f64;
+                let offset = (t.clamp(0.0, 1.0) * width + 0.5) as u128;
+
+                // the offset may not fit into $inner by itself, but the sum
+                // always will
+                MIN.wrapping_add(offset as $inner)
+            }
+
+            /// Linearly maps the value into another set of bounds, rounding to
+            /// the nearest value. The position is calculated as an `f64`, so
+            /// some precision is lost for ranges wider than 2^53.
+            pub fn remap<const OTHER_MIN: $inner, const OTHER_MAX: $inner>(
+                &self,
+            ) -> $other {
+                <$other>::from_normalized(self.normalize())
+            }
+        }
+    };
+}
+pub(crate) use impl_normalize;
+
+// The float types take their bounds from a marker type B rather than from
+// const generics, so they need their own versions of the above. Like the
+// integer types, arithmetic returns the raw inner value.
+macro_rules! impl_float {
+    ($type:ident, $inner:ty) => {
+        impl<B: FloatBounds<$inner>> $type<B> {
+            pub fn inner(&self) -> $inner { self.0 }
+            pub fn into_inner(self) -> $inner { self.0 }
+            pub fn min_bound(&self) -> $inner { B::MIN }
+            pub fn max_bound(&self) -> $inner { B::MAX }
+        }
+
+        impl_float_arith!($type, $inner, Add, add, +);
+        impl_float_arith!($type, $inner, Sub, sub, -);
+        impl_float_arith!($type, $inner, Mul, mul, *);
+        impl_float_arith!($type, $inner, Div, div, /);
+        impl_float_arith!($type, $inner, Rem, rem, %);
+
+        impl<B: FloatBounds<$inner>> From<$type<B>> for $inner {
+            fn from(value: $type<B>) -> $inner { value.0 }
+        }
+
+        impl<B: FloatBounds<$inner>> PartialEq<$inner> for $type<B> {
+            fn eq(&self, other: &$inner) -> bool { self.0 == *other }
+        }
+        impl<B: FloatBounds<$inner>, OTHER: FloatBounds<$inner>>
+            PartialEq<$type<OTHER>> for $type<B>
+        {
+            fn eq(&self, other: &$type<OTHER>) -> bool { self.0 == other.0 }
+        }
+
+        impl<B: FloatBounds<$inner>> PartialOrd<$inner> for $type<B> {
+            fn partial_cmp(&self, other: &$inner) -> Option<Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+        impl<B: FloatBounds<$inner>, OTHER: FloatBounds<$inner>>
+            PartialOrd<$type<OTHER>> for $type<B>
+        {
+            fn partial_cmp(&self, other: &$type<OTHER>) -> Option<Ordering> {
+                self.0.partial_cmp(&other.0)
+            }
+        }
+
+        /// The default value is `MIN`, as it's the only value guaranteed to be
+        /// within bounds.
+        impl<B: FloatBounds<$inner>> Default for $type<B> {
+            fn default() -> Self {
+                assert!(B::MIN < B::MAX, "MIN must be less than MAX");
+                Self(B::MIN, PhantomData)
+            }
+        }
+
+        impl<B> fmt::Display for $type<B> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        // B is only a marker, so it doesn't need to implement any of these
+        impl<B> fmt::Debug for $type<B> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($type)).field(&self.0).finish()
+            }
+        }
+        impl<B> Clone for $type<B> {
+            fn clone(&self) -> Self { *self }
+        }
+        impl<B> Copy for $type<B> {}
+    };
+}
+pub(crate) use impl_float;
+
+macro_rules! impl_clamped_float {
+    ($type:ident, $inner:ty, $output:ty, $impl:expr) => {
+        impl<B: FloatBounds<$inner>> Clamped for $type<B> {
+            type Inner = $inner;
+            type Output = $output;
+
+            fn inner(&self) -> $inner { self.0 }
+            fn min_bound(&self) -> $inner { B::MIN }
+            fn max_bound(&self) -> $inner { B::MAX }
+            fn clamp(&self, value: $inner) -> $output { $impl(value) }
+        }
+    };
+}
+pub(crate) use impl_clamped_float;
+
+macro_rules! impl_float_arith {
+    ($type:ident, $inner:ty, $trait:ident, $fn:ident, $op:tt) => {
+        impl<B: FloatBounds<$inner>> $trait<$inner> for $type<B> {
+            type Output = $inner;
+            fn $fn(self, other: $inner) -> Self::Output { self.0 $op other }
+        }
+
+        impl<B: FloatBounds<$inner>, OTHER: FloatBounds<$inner>>
+            $trait<$type<OTHER>> for $type<B>
+        {
+            type Output = $inner;
+            fn $fn(self, other: $type<OTHER>) -> Self::Output {
+                self.0 $op other.0
+            }
+        }
+    };
+}
+pub(crate) use impl_float_arith;
+
+// Assigning arithmetic for float types that can always be built with From.
+macro_rules! impl_float_arith_assign {
+    ($type:ident, $inner:ty) => {
+        impl_float_arith_assign!($type, $inner, AddAssign, add_assign, +);
+        impl_float_arith_assign!($type, $inner, SubAssign, sub_assign, -);
+        impl_float_arith_assign!($type, $inner, MulAssign, mul_assign, *);
+        impl_float_arith_assign!($type, $inner, DivAssign, div_assign, /);
+        impl_float_arith_assign!($type, $inner, RemAssign, rem_assign, %);
+    };
+    ($type:ident, $inner:ty, $trait:ident, $fn:ident, $op:tt) => {
+        impl<B: FloatBounds<$inner>> $trait<$inner> for $type<B> {
+            fn $fn(&mut self, other: $inner) {
+                *self = Self::from(self.0 $op other)
+            }
+        }
+
+        impl<B: FloatBounds<$inner>, OTHER: FloatBounds<$inner>>
+            $trait<$type<OTHER>> for $type<B>
+        {
+            fn $fn(&mut self, other: $type<OTHER>) {
+                *self = Self::from(self.0 $op other.0)
+            }
+        }
+    };
+}
+pub(crate) use impl_float_arith_assign;
+
+// Stepping for types where values never wrap around, so iteration simply
+// stops if `end` isn't greater than `start`.
+macro_rules! impl_step {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Returns an iterator from `start` up to (but not including)
+            /// `end`, advancing by `step` each time. Panics if `step` is zero.
+            pub fn step_by(start: Self, end: Self, step: usize) -> Steps<Self> {
+                let distance = if end.0 > start.0 {
+                    end.0.abs_diff(start.0) as u128
+                } else {
+                    0
+                };
+                Steps::new(start, distance, step, |start, distance| {
+                    Self(start.0.wrapping_add(distance as $inner))
+                })
+            }
+        }
+    };
+}
+pub(crate) use impl_step;
+
+// Sum and Product for types that clamp on assignment. The clamping is applied
+// after every step, so the result matches folding with += or *=.
+macro_rules! impl_sum {
+    ($type:ty, $inner:ty) => {
+        /// Starts from zero, clamped into range (so `MIN` if zero is out of
+        /// range), then adds each value in turn.
+        impl<const MIN: $inner, const MAX: $inner> Sum<$inner> for $type {
+            fn sum<I: Iterator<Item = $inner>>(iter: I) -> Self {
+                iter.fold(Self::from(0), |mut acc, x| {
+                    acc += x;
+                    acc
+                })
+            }
+        }
+        impl<const MIN: $inner, const MAX: $inner> Sum for $type {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.map(|x| x.0).sum()
+            }
+        }
+
+        /// Starts from one, clamped into range, then multiplies by each value
+        /// in turn.
+        impl<const MIN: $inner, const MAX: $inner> Product<$inner> for $type {
+            fn product<I: Iterator<Item = $inner>>(iter: I) -> Self {
+                iter.fold(Self::from(1), |mut acc, x| {
+                    acc *= x;
+                    acc
+                })
+            }
+        }
+        impl<const MIN: $inner, const MAX: $inner> Product for $type {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.map(|x| x.0).product()
+            }
+        }
+    };
+}
+pub(crate) use impl_sum;
+
+// Implements Clamped by deferring to the type's own methods. $impl is given
+// the value to clamp, and should return $output.
+macro_rules! impl_clamped {
+    ($type:ty, $inner:ty, $output:ty, $impl:expr) => {
+        impl<const MIN: $inner, const MAX: $inner> Clamped for $type {
+            type Inner = $inner;
+            type Output = $output;
+
+            fn inner(&self) -> $inner { self.inner() }
+            fn min_bound(&self) -> $inner { MIN }
+            fn max_bound(&self) -> $inner { MAX }
+            fn clamp(&self, value: $inner) -> $output { $impl(value) }
+        }
+    };
+}
+pub(crate) use impl_clamped;
+
+// Moves the inner value into another set of bounds as-is, clamping it with
+// $impl (unlike remap(), which scales it).
+macro_rules! impl_rebound {
+    ($type:ty, $other:ty, $inner:ty, $output:ty, $impl:expr) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Moves the value into another set of bounds without changing it,
+            /// applying the usual clamping if it falls outside of them.
+            pub fn rebound<const OTHER_MIN: $inner, const OTHER_MAX: $inner>(
+                self,
+            ) -> $output {
+                $impl(self.inner())
+            }
+        }
+    };
+}
+pub(crate) use impl_rebound;
+
+// The midpoint is always in range, even when MAX is exclusive, so $impl is
+// only used to construct the value.
+macro_rules! impl_midpoint {
+    ($type:ty, $inner:ty, $impl:expr) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// The value halfway between `MIN` and `MAX`, rounded towards
+            /// `MIN`. This is calculated without overflowing.
+            pub const fn midpoint_value() -> $inner {
+                MIN.wrapping_add((MAX.abs_diff(MIN) / 2) as $inner)
+            }
+            pub fn midpoint() -> Self { $impl(Self::midpoint_value()) }
+        }
+    };
+}
+pub(crate) use impl_midpoint;
+
+// In-place modification for the types that can always be clamped with From.
+macro_rules! impl_inner_mut {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Overwrites the value, clamping it as usual.
+            pub fn set(&mut self, value: $inner) { *self = Self::from(value) }
+
+            /// Returns a guard that allows the inner value to be modified
+            /// directly. It is clamped back into range when the guard drops.
+            pub fn inner_mut(&mut self) -> ClampGuard<'_, Self, $inner> {
+                let inner = self.inner();
+                ClampGuard::new(self, inner, |this, inner| {
+                    *this = Self::from(inner)
+                })
+            }
+        }
+    };
+}
+pub(crate) use impl_inner_mut;
+
+// In-place modification for the bounded types. These can fail, and inner_mut()
+// needs to be told what to do if the value is out of range when the guard
+// drops.
+macro_rules! impl_inner_mut_bounded {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Overwrites the value, or leaves it unchanged if `value` is out
+            /// of range.
+            pub fn try_set(
+                &mut self, value: $inner,
+            ) -> Result<(), BoundsError> {
+                *self = Self::try_from(value)?;
+                Ok(())
+            }
+
+            /// Returns a guard that allows the inner value to be modified
+            /// directly. If the value is out of range when the guard drops,
+            /// the changes are discarded. See [`Self::inner_mut_with`] to
+            /// panic instead.
+            pub fn inner_mut(&mut self) -> ClampGuard<'_, Self, $inner> {
+                self.inner_mut_with(OutOfBounds::Reset)
+            }
+
+            /// As [`Self::inner_mut`], but with a choice of what to do if the
+            /// value is out of range when the guard drops.
+            pub fn inner_mut_with(
+                &mut self, on_invalid: OutOfBounds,
+            ) -> ClampGuard<'_, Self, $inner> {
+                let inner = self.inner();
+                let commit: fn(&mut Self, $inner) = match on_invalid {
+                    OutOfBounds::Reset => |this, inner| {
+                        if let Ok(value) = Self::try_from(inner) {
+                            *this = value
+                        }
+                    },
+                    OutOfBounds::Panic => |this, inner| {
+                        *this = Self::try_from(inner)
+                            .expect("value must be in range when guard drops")
+                    },
+                };
+                ClampGuard::new(self, inner, commit)
+            }
+        }
+    };
+}
+pub(crate) use impl_inner_mut_bounded;
+
+// num_traits::Bounded gives the smallest and largest values that are actually
+// in range, which for the exclusive types is MAX - 1.
+//
+// Zero, One, and Num can't be implemented, as they require the arithmetic
+// operators to return Self rather than the inner type.
+#[cfg(feature = "num-traits")]
+macro_rules! impl_num_bounded {
+    ($type:ty, $inner:ty, $min:expr, $max:expr) => {
+        impl<const MIN: $inner, const MAX: $inner> num_traits::Bounded
+            for $type
+        {
+            fn min_value() -> Self { $min }
+            fn max_value() -> Self { $max }
+        }
+    };
+}
+#[cfg(feature = "num-traits")]
+pub(crate) use impl_num_bounded;
+
+// Serializes a concrete type as its inner value only. The bounds live in the
+// type itself, so they never need to be written out.
+//
+// When deserializing, the inner value is passed through $impl so that the
+// usual clamping logic is applied (or an error is raised).
+#[cfg(feature = "serde")]
+macro_rules! impl_serde {
+    ($type:ty, $inner:ty, $impl:expr) => {
+        impl<const MIN: $inner, const MAX: $inner> serde::Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.inner().serialize(serializer)
+            }
+        }
+
+        impl<'de, const MIN: $inner, const MAX: $inner> serde::Deserialize<'de>
+            for $type
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let inner = <$inner>::deserialize(deserializer)?;
+                $impl(inner)
+            }
+        }
+    };
+}
+#[cfg(feature = "serde")]
+pub(crate) use impl_serde;
+
+// The same as impl_serde, but for the float types.
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_float {
+    ($type:ident, $inner:ty, $impl:expr) => {
+        impl<B> serde::Serialize for $type<B> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.0.serialize(serializer)
+            }
+        }
+
+        impl<'de, B: FloatBounds<$inner>> serde::Deserialize<'de> for $type<B> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let inner = <$inner>::deserialize(deserializer)?;
+                $impl(inner)
+            }
+        }
+    };
+}
+#[cfg(feature = "serde")]
+pub(crate) use impl_serde_float;
+
+// Generic types carry their bounds at runtime, so all three fields need to be
+// written out. When deserializing, $impl is given the (inner, min, max) triple
+// and is responsible for upholding the type's invariants (usually via try_new).
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_generic {
+    ($type:ident, [$($bound:tt)+], $impl:expr) => {
+        const _: () = {
+            use serde::ser::SerializeStruct;
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            impl<T: Serialize> Serialize for $type<T> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let mut state =
+                        serializer.serialize_struct(stringify!($type), 3)?;
+                    state.serialize_field("inner", &self.inner)?;
+                    state.serialize_field("min", &self.bounds.min)?;
+                    state.serialize_field("max", &self.bounds.max)?;
+                    state.end()
+                }
+            }
+
+            #[derive(Deserialize)]
+            struct Raw<T> {
+                inner: T,
+                min:   T,
+                max:   T,
+            }
+
+            impl<'de, T: Deserialize<'de> + $($bound)+> Deserialize<'de>
+                for $type<T>
+            {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let Raw { inner, min, max } =
+                        Raw::deserialize(deserializer)?;
+                    $impl(inner, min, max)
+                }
+            }
+        };
+    };
+}
+#[cfg(feature = "serde")]
+pub(crate) use impl_serde_generic;
+
+/// Clamps a value into a range inline, without naming a concrete type. The
+/// first argument is the strategy (`saturating` or `wrapping`), and the range
+/// may be either exclusive or inclusive:
+///
+/// ```rust
+/// use clamps::clamp;
+///
+/// assert_eq!(clamp!(saturating, 150, 0..=100), 100);
+/// assert_eq!(clamp!(saturating, 150, 0..100), 99);
+/// assert_eq!(clamp!(wrapping, 370, 0..360), 10);
+/// assert_eq!(clamp!(wrapping, -1, 0..=359), 359);
+/// ```
+///
+/// This works for any primitive integer type, and panics if the range is
+/// empty.
+#[macro_export]
+macro_rules! clamp {
+    (saturating, $value:expr, $range:expr) => {
+        $crate::ClampRange::saturate(&($range), $value)
+    };
+    (wrapping, $value:expr, $range:expr) => {
+        $crate::ClampRange::wrap(&($range), $value)
+    };
+}
+
+// The ranges that clamp!() accepts. This has to be public for the macro to use,
+// but isn't meant to be used directly.
+#[doc(hidden)]
+pub trait ClampRange<T> {
+    fn saturate(&self, value: T) -> T;
+    fn wrap(&self, value: T) -> T;
+}
+
+// Saturating is left to Ord::clamp, while wrapping uses the same functions as
+// the concrete wrapping types. For inclusive ranges, the width is one larger,
+// which only overflows when the range covers every value.
+macro_rules! impl_clamp_range {
+    ($($inner:ident),* $(,)?) => {$(
+        impl ClampRange<$inner> for core::ops::Range<$inner> {
+            fn saturate(&self, value: $inner) -> $inner {
+                assert!(self.start < self.end, "the range must not be empty");
+                value.clamp(self.start, self.end - 1)
+            }
+
+            fn wrap(&self, value: $inner) -> $inner {
+                assert!(self.start < self.end, "the range must not be empty");
+                let width = self.end.abs_diff(self.start);
+                wrap::$inner(value, self.start, width)
+            }
+        }
+
+        impl ClampRange<$inner> for core::ops::RangeInclusive<$inner> {
+            fn saturate(&self, value: $inner) -> $inner {
+                let (start, end) = (*self.start(), *self.end());
+                assert!(start <= end, "the range must not be empty");
+                value.clamp(start, end)
+            }
+
+            fn wrap(&self, value: $inner) -> $inner {
+                let (start, end) = (*self.start(), *self.end());
+                assert!(start <= end, "the range must not be empty");
+                match end.abs_diff(start).checked_add(1) {
+                    Some(width) => wrap::$inner(value, start, width),
+                    None => value,
+                }
+            }
+        }
+    )*};
+}
+
+impl_clamp_range!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+);
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn clamp_saturating() {
+        assert_eq!(clamp!(saturating, 150, 0..=100), 100);
+        assert_eq!(clamp!(saturating, -5, 0..=100), 0);
+        assert_eq!(clamp!(saturating, 50u8, 0..=100), 50);
+        assert_eq!(clamp!(saturating, 150, 0..100), 99);
+        assert_eq!(clamp!(saturating, -150i64, -100..100), -100);
+        assert_eq!(clamp!(saturating, 255u8, 0..=u8::MAX), 255);
+    }
+
+    #[test]
+    fn clamp_wrapping() {
+        assert_eq!(clamp!(wrapping, 370, 0..360), 10);
+        assert_eq!(clamp!(wrapping, -1, 0..360), 359);
+        assert_eq!(clamp!(wrapping, -360, 0..360), 0);
+        assert_eq!(clamp!(wrapping, 200u8, 2..8), 2);
+
+        assert_eq!(clamp!(wrapping, 360, 0..=359), 0);
+        assert_eq!(clamp!(wrapping, -1, 0..=359), 359);
+        assert_eq!(clamp!(wrapping, -360, 0..=359), 0);
+        assert_eq!(clamp!(wrapping, 9u8, 2..=8), 2);
+        assert_eq!(clamp!(wrapping, 0u8, 2..=8), 7);
+        assert_eq!(clamp!(wrapping, i8::MIN, -100..=i8::MAX), 100);
+        assert_eq!(clamp!(wrapping, 200u8, 0..=u8::MAX), 200);
+    }
+
+    #[test]
+    fn clamp_wrapping_matches_the_concrete_types() {
+        use crate::wrapping::{WrappingI8, WrappingU8};
+
+        for value in i8::MIN..=i8::MAX {
+            let expected = WrappingI8::<-100, 27>::from(value).inner();
+            assert_eq!(clamp!(wrapping, value, -100..27), expected);
+            assert_eq!(clamp!(wrapping, value, -100..=26), expected);
+        }
+        for value in u8::MIN..=u8::MAX {
+            let expected = WrappingU8::<200, 255>::from(value).inner();
+            assert_eq!(clamp!(wrapping, value, 200..255), expected);
+            assert_eq!(clamp!(wrapping, value, 200..=254), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_empty_range() { let _ = clamp!(wrapping, 5, 3..3); }
+}
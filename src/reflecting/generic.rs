@@ -0,0 +1,474 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, RangeInclusive, Rem,
+    RemAssign, Sub, SubAssign,
+};
+
+use crate::bounded::BoundsError;
+use crate::bounds::Bounds;
+use crate::builder::Builder;
+use crate::clamped::Clamped;
+
+pub struct Reflecting<T> {
+    inner:  T,
+    bounds: Bounds<T>,
+}
+
+impl<
+        T: PartialOrd
+            + Clone
+            + Add<Output = T>
+            + Rem<Output = T>
+            + Sub<Output = T>,
+    > Reflecting<T>
+{
+    /// Panics if `min` is not less than `max`. See [`Reflecting::try_new`] for
+    /// a fallible alternative.
+    pub fn new(inner: T, min: T, max: T) -> Self {
+        match Self::try_new(inner, min, max) {
+            Ok(reflecting) => reflecting,
+            Err(_) => panic!("MIN value must be less than MAX"),
+        }
+    }
+
+    pub fn try_new(inner: T, min: T, max: T) -> Result<Self, BoundsError> {
+        Ok(Self::with_bounds(inner, Bounds::try_new(min, max)?))
+    }
+
+    pub fn with_bounds(mut inner: T, bounds: Bounds<T>) -> Self {
+        let Bounds { min, max } = &bounds;
+
+        // Reflection is symmetric around min, so only the distance from it
+        // matters. The pattern then repeats every two widths.
+        if inner > *max || inner < *min {
+            let width = bounds.width();
+            let distance = if inner >= *min {
+                inner - min.clone()
+            } else {
+                min.clone() - inner
+            };
+
+            let rem = distance % (width.clone() + width.clone());
+            if rem > width {
+                inner = max.clone() - (rem - width);
+            } else {
+                inner = min.clone() + rem;
+            }
+        }
+
+        Self { inner, bounds }
+    }
+
+    /// Returns a [`Builder`], which names each argument explicitly.
+    pub fn builder() -> Builder<T, Self> {
+        Builder::new(|inner, bounds| Ok(Self::with_bounds(inner, bounds)))
+    }
+
+    pub fn inner(&self) -> &T { &self.inner }
+    pub fn into_inner(self) -> T { self.inner }
+    pub fn bounds(&self) -> &Bounds<T> { &self.bounds }
+    pub fn contains(&self, value: &T) -> bool {
+        self.bounds.contains_inclusive(value)
+    }
+    pub fn range(&self) -> RangeInclusive<&T> { self.bounds.range_inclusive() }
+    pub fn min_bound(&self) -> &T { &self.bounds.min }
+    pub fn max_bound(&self) -> &T { &self.bounds.max }
+}
+
+impl<T: PartialOrd> Reflecting<T> {
+    /// Returns whichever value is larger, or `other` if they're equal.
+    pub fn max_with(self, other: Self) -> Self {
+        if self.inner > other.inner {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns whichever value is smaller, or `self` if they're equal.
+    pub fn min_with(self, other: Self) -> Self {
+        if other.inner < self.inner {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<
+        T: PartialOrd
+            + Clone
+            + Add<Output = T>
+            + Rem<Output = T>
+            + Sub<Output = T>,
+    > Clamped for Reflecting<T>
+{
+    type Inner = T;
+    type Output = Self;
+
+    fn inner(&self) -> T { self.inner.clone() }
+    fn min_bound(&self) -> T { self.bounds.min.clone() }
+    fn max_bound(&self) -> T { self.bounds.max.clone() }
+    fn clamp(&self, value: T) -> Self {
+        Reflecting::with_bounds(value, self.bounds.clone())
+    }
+}
+
+//arithmetic
+macro_rules! impl_arith {
+    ($trait:ident, $fn:ident, $impl:expr) => {
+        impl<T: $trait> $trait<T> for Reflecting<T> {
+            type Output = T::Output;
+            fn $fn(self, other: T) -> Self::Output { $impl(self.inner, other) }
+        }
+
+        impl<T: $trait> $trait<Reflecting<T>> for Reflecting<T> {
+            type Output = T::Output;
+            fn $fn(self, other: Reflecting<T>) -> Self::Output {
+                $impl(self.inner, other.inner)
+            }
+        }
+    };
+}
+
+impl_arith!(Add, add, |this, other| this + other);
+impl_arith!(Sub, sub, |this, other| this - other);
+impl_arith!(Mul, mul, |this, other| this * other);
+impl_arith!(Div, div, |this, other| this / other);
+impl_arith!(Rem, rem, |this, other| this % other);
+
+// assigning arithmetic
+macro_rules! impl_arith_assign {
+    ($trait:ident, $fn:ident, $impl:expr) => {
+        impl<
+                T: PartialOrd
+                    + Copy
+                    + Add<Output = T>
+                    + Sub<Output = T>
+                    + Mul<Output = T>
+                    + Div<Output = T>
+                    + Rem<Output = T>
+                    + $trait,
+            > $trait<T> for Reflecting<T>
+        {
+            fn $fn(&mut self, other: T) {
+                let result = $impl(*self, other);
+                *self = Reflecting::with_bounds(result, self.bounds)
+            }
+        }
+
+        impl<
+                T: PartialOrd
+                    + Copy
+                    + Add<Output = T>
+                    + Sub<Output = T>
+                    + Mul<Output = T>
+                    + Div<Output = T>
+                    + Rem<Output = T>
+                    + $trait,
+            > $trait<Reflecting<T>> for Reflecting<T>
+        {
+            fn $fn(&mut self, other: Reflecting<T>) {
+                let result = $impl(*self, other);
+                *self = Reflecting::with_bounds(result, self.bounds)
+            }
+        }
+    };
+}
+
+impl_arith_assign!(AddAssign, add_assign, |this, other| this + other);
+impl_arith_assign!(MulAssign, mul_assign, |this, other| this * other);
+impl_arith_assign!(DivAssign, div_assign, |this, other| this / other);
+impl_arith_assign!(RemAssign, rem_assign, |this, other| this % other);
+
+fn reflecting_sub<T: Copy + PartialOrd + Sub<Output = T> + Add<Output = T>>(
+    this: Reflecting<T>, other: T,
+) -> T {
+    // if we'd go below MIN, there's a chance of underflowing before we get
+    // the chance to reflect. Reflection is symmetric around MIN, so we can
+    // reflect off of it in advance instead.
+    let offset = this.inner - this.bounds.min;
+    if other > offset {
+        return this.bounds.min + (other - offset);
+    }
+    this.inner - other
+}
+
+impl<
+        T: PartialOrd
+            + Copy
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>,
+    > SubAssign<T> for Reflecting<T>
+{
+    fn sub_assign(&mut self, other: T) {
+        let result = reflecting_sub(*self, other);
+        *self = Reflecting::with_bounds(result, self.bounds)
+    }
+}
+
+impl<
+        T: PartialOrd
+            + Copy
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>,
+    > SubAssign<Reflecting<T>> for Reflecting<T>
+{
+    fn sub_assign(&mut self, other: Reflecting<T>) {
+        self.sub_assign(other.inner)
+    }
+}
+
+// negation
+impl<
+        T: PartialOrd
+            + Clone
+            + Add<Output = T>
+            + Rem<Output = T>
+            + Sub<Output = T>
+            + Neg<Output = T>,
+    > Neg for Reflecting<T>
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Reflecting::with_bounds(-self.inner, self.bounds)
+    }
+}
+
+// equality
+impl<T: PartialEq> PartialEq<T> for Reflecting<T> {
+    fn eq(&self, other: &T) -> bool { self.inner == *other }
+}
+impl<T: PartialEq> PartialEq<Reflecting<T>> for Reflecting<T> {
+    fn eq(&self, other: &Reflecting<T>) -> bool { self.inner == other.inner }
+}
+impl<T: Eq> Eq for Reflecting<T> {}
+
+// hashing has to agree with equality, so the bounds are ignored
+impl<T: Hash> Hash for Reflecting<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.inner.hash(state) }
+}
+
+// Display only shows the inner value
+impl<T: fmt::Display> fmt::Display for Reflecting<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+// We can only implement Debug if T implements Debug
+impl<T: fmt::Debug> fmt::Debug for Reflecting<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Reflecting {{inner: {:?}, min: {:?}, max: {:?}}}",
+            self.inner, self.bounds.min, self.bounds.max
+        )
+    }
+}
+
+// We can only implement Clone if T implements Clone
+impl<T: Clone> Clone for Reflecting<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner:  self.inner.clone(),
+            bounds: self.bounds.clone(),
+        }
+    }
+}
+impl<T: Copy> Copy for Reflecting<T> {}
+
+#[cfg(feature = "serde")]
+crate::macros::impl_serde_generic!(
+    Reflecting,
+    [PartialOrd + Clone + Add<Output = T> + Rem<Output = T> + Sub<Output = T>],
+    |inner, min, max| Reflecting::try_new(inner, min, max)
+        .map_err(serde::de::Error::custom)
+);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create() {
+        let foo = Reflecting::new(3, -5, 74);
+        assert_eq!(foo.into_inner(), 3)
+    }
+
+    #[test]
+    fn test_debug() {
+        let foo = Reflecting::new(3, -5, 74);
+        let out = format!("{:?}", foo);
+        assert_eq!(&out, "Reflecting {inner: 3, min: -5, max: 74}")
+    }
+
+    #[test]
+    fn test_eq() {
+        let foo = Reflecting::new(3, -5, 74);
+        let bar = Reflecting::new(3, 0, 74);
+        assert_eq!(foo, 3);
+        assert_eq!(bar, 3);
+        assert_eq!(foo, bar);
+    }
+
+    #[test]
+    fn test_contains() {
+        let foo = Reflecting::new(3, -2, 8);
+        assert!(foo.contains(&-2));
+        assert!(foo.contains(&8));
+        assert!(!foo.contains(&9));
+        assert!(!foo.contains(&-3));
+    }
+
+    #[test]
+    fn test_with_bounds() {
+        let bounds = Bounds::new(0, 10);
+        let foo = Reflecting::with_bounds(13, bounds);
+        assert_eq!(foo, 7);
+        assert_eq!(foo.bounds(), &bounds);
+        assert_eq!(Reflecting::with_bounds(-1, bounds), 1);
+    }
+
+    #[test]
+    fn test_max_and_min() {
+        let foo = Reflecting::new(4, -3, 8);
+        assert_eq!(foo.min_bound(), &-3);
+        assert_eq!(foo.max_bound(), &8);
+        assert_eq!(foo.range(), &-3..=&8);
+    }
+
+    #[test]
+    fn test_arith() {
+        let foo = Reflecting::new(5.0, -100.0, 100.0);
+        for i in -10..10 {
+            let num = i as f64;
+            assert_eq!(foo + num, foo.inner() + num);
+            assert_eq!(foo - num, foo.inner() - num);
+            assert_eq!(foo * num, foo.inner() * num);
+
+            if num != 0.0 {
+                assert_eq!(foo / num, foo.inner() / num);
+                assert_eq!(foo % num, foo.inner() % num);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reflecting() {
+        assert_eq!(Reflecting::new(10, 0, 10), 10);
+        assert_eq!(Reflecting::new(13, 0, 10), 7);
+        // 25 -> 10 -> 0 -> 5
+        assert_eq!(Reflecting::new(25, 0, 10), 5);
+        // 38 -> 10 -> 0 -> 10 -> 2
+        assert_eq!(Reflecting::new(38, 0, 10), 2);
+        assert_eq!(Reflecting::new(12.5, 0.0, 10.0), 7.5);
+    }
+
+    #[test]
+    fn test_reflecting_below_min() {
+        assert_eq!(Reflecting::new(3, 5, 9), 7);
+        assert_eq!(Reflecting::new(0, 5, 9), 8);
+        // -35 -> -10 -> 10 -> 5
+        assert_eq!(Reflecting::new(-35, -10, 10), 5);
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut foo = Reflecting::new(4, 0, 10);
+        foo += 9;
+        assert_eq!(foo, 7);
+        foo += 23;
+        assert_eq!(foo, 10);
+        foo *= 2;
+        assert_eq!(foo, 0);
+    }
+
+    #[test]
+    fn test_reflecting_backward() {
+        let mut foo = Reflecting::new(4_u32, 0, 10);
+        foo -= 7;
+        assert_eq!(foo, 3);
+        foo -= 15;
+        assert_eq!(foo, 8);
+
+        let mut bar = Reflecting::new(6_u32, 4, 8);
+        bar -= 4000001;
+        assert_eq!(bar, Reflecting::new(4000003, 4, 8));
+    }
+
+    #[test]
+    fn test_matches_concrete() {
+        use crate::reflecting::ReflectingI32;
+
+        for value in -60..60 {
+            let expected = ReflectingI32::<-7, 4>::from(value).inner();
+            assert_eq!(Reflecting::new(value, -7, 4), expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let foo = Reflecting::new(3, -5, 74);
+        let json = serde_json::to_string(&foo).unwrap();
+        assert_eq!(json, r#"{"inner":3,"min":-5,"max":74}"#);
+        let bar: Reflecting<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bar, foo);
+        assert_eq!(bar.range(), foo.range());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_deserialize_will_reflect() {
+        let json = r#"{"inner":13,"min":0,"max":10}"#;
+        let foo: Reflecting<i32> = serde_json::from_str(json).unwrap();
+        assert_eq!(foo, 7);
+
+        let json = r#"{"inner":3,"min":10,"max":10}"#;
+        assert!(serde_json::from_str::<Reflecting<i32>>(json).is_err());
+    }
+
+    #[test]
+    fn test_hash() {
+        use crate::test_utils::hash_of;
+
+        let foo = Reflecting::new(3, -5, 74);
+        let bar = Reflecting::new(3, 0, 74);
+        assert_eq!(foo, bar);
+        assert_eq!(hash_of(&foo), hash_of(&bar));
+    }
+
+    #[test]
+    fn test_display() {
+        let foo = Reflecting::new(3.5, -5.0, 74.0);
+        assert_eq!(format!("{}", foo), "3.5");
+        assert_eq!(format!("{:.2}", foo), "3.50");
+    }
+
+    #[test]
+    fn test_neg() {
+        let foo = Reflecting::new(8, -5, 10);
+        assert_eq!(-foo, -2);
+    }
+
+    #[test]
+    fn test_try_new() {
+        use BoundsError::*;
+        assert_eq!(Reflecting::try_new(13, 0, 10).unwrap(), 7);
+        assert_eq!(Reflecting::try_new(3, 5, 5), Err(InvalidBounds));
+        assert_eq!(Reflecting::try_new(3, 10, 5), Err(InvalidBounds));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_use_invalid_bounds() { let _ = Reflecting::new(3, 10, 5); }
+}
@@ -0,0 +1,777 @@
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::num::ParseIntError;
+use core::ops::{
+    Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Neg, RangeInclusive,
+    Rem, RemAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+use crate::clamped::Clamped;
+use crate::guard::ClampGuard;
+use crate::iter::{All, Steps};
+use crate::macros::*;
+
+macro_rules! impl_create {
+    ($type:ty, $inner:ty, $uinner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            pub fn new(inner: $inner) -> Self { Self::from(inner) }
+            pub fn inner(&self) -> $inner { self.0 }
+
+            /// A `const` version of [`Self::new`], for use in `const` and
+            /// `static` items.
+            pub const fn new_const(inner: $inner) -> Self {
+                let () = Self::VALID_BOUNDS;
+
+                // Reflection is symmetric around MIN, so values below it can
+                // be treated as if they were the same distance above it.
+                Self(Self::reflect_up(0, inner.abs_diff(MIN)))
+            }
+
+            /// A `const` version of [`Self::inner`].
+            pub const fn inner_const(&self) -> $inner { self.0 }
+            pub fn range(&self) -> RangeInclusive<$inner> { MIN..=MAX }
+            pub fn min_bound(&self) -> $inner { MIN }
+            pub fn max_bound(&self) -> $inner { MAX }
+
+            pub fn is_at_min(&self) -> bool { self.0 == MIN }
+            pub fn is_at_max(&self) -> bool { self.0 == MAX }
+
+            /// Whether `inner` is already in range, and so would be left
+            /// unchanged by [`From::from`].
+            pub fn contains(inner: $inner) -> bool {
+                inner >= MIN && inner <= MAX
+            }
+
+            /// An alias for [`From::from`].
+            pub fn clamp(inner: $inner) -> Self { Self::from(inner) }
+
+            /// Returns an iterator over every value in `MIN..=MAX`.
+            pub fn all() -> All<Self> {
+                let () = Self::VALID_BOUNDS;
+                let last = MAX.abs_diff(MIN) as u128;
+                All::new(last, |offset| {
+                    Self(MIN.wrapping_add(offset as $inner))
+                })
+            }
+        }
+
+        // Reflection is done on offsets from MIN. These are stored in the
+        // unsigned form of $inner, which can always hold MAX - MIN without
+        // overflowing.
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            const fn offset(&self) -> $uinner { self.0.abs_diff(MIN) }
+
+            // Moves upwards from `offset` by `distance`, bouncing off of the
+            // bounds as many times as needed.
+            const fn reflect_up(offset: $uinner, distance: $uinner) -> $inner {
+                // the pattern repeats every two widths, so anything beyond
+                // that can be dropped (if two widths fit into $uinner at all)
+                let distance = match Self::width().checked_mul(2) {
+                    Some(period) => distance % period,
+                    None => distance,
+                };
+
+                let to_max = Self::width() - offset;
+                if distance <= to_max {
+                    MIN.wrapping_add((offset + distance) as $inner)
+                } else if distance - to_max <= Self::width() {
+                    MAX.wrapping_sub((distance - to_max) as $inner)
+                } else {
+                    let rem = distance - to_max - Self::width();
+                    MIN.wrapping_add(rem as $inner)
+                }
+            }
+
+            // Moves downwards from `offset` by `distance`. After the first
+            // bounce off of MIN, this is the same as moving upwards from it.
+            const fn reflect_down(
+                offset: $uinner, distance: $uinner,
+            ) -> $inner {
+                if distance <= offset {
+                    MIN.wrapping_add((offset - distance) as $inner)
+                } else {
+                    Self::reflect_up(0, distance - offset)
+                }
+            }
+
+            // The reflection repeats every two widths, so products that
+            // overflow are found modulo that instead. Two widths might not fit
+            // in $uinner, so each value is kept as (value % width, whether
+            // value / width is odd).
+            const fn split(value: $uinner) -> ($uinner, bool) {
+                (value % Self::width(), (value / Self::width()) % 2 == 1)
+            }
+            const fn add_period(
+                a: ($uinner, bool), b: ($uinner, bool),
+            ) -> ($uinner, bool) {
+                let gap = Self::width() - b.0;
+                if a.0 >= gap {
+                    (a.0 - gap, a.1 == b.1)
+                } else {
+                    (a.0 + b.0, a.1 != b.1)
+                }
+            }
+            const fn neg_period(a: ($uinner, bool)) -> ($uinner, bool) {
+                match a {
+                    (0, odd) => (0, odd),
+                    (rem, odd) => (Self::width() - rem, !odd),
+                }
+            }
+            const fn mul_period(a: $uinner, mut b: $uinner) -> ($uinner, bool) {
+                let mut a = Self::split(a);
+                let mut result = (0, false);
+                while b > 0 {
+                    if b & 1 == 1 {
+                        result = Self::add_period(result, a);
+                    }
+                    a = Self::add_period(a, a);
+                    b >>= 1;
+                }
+                result
+            }
+
+            // The reflection of -$inner::MIN, for when MIN is $inner::MIN.
+            // This is one greater than $inner::MAX, which is too far from MIN
+            // to fit in $uinner, so the first step up is taken as the starting
+            // offset instead.
+            const fn neg_inner_min() -> $inner {
+                Self::reflect_up(1, MIN.abs_diff(<$inner>::MAX))
+            }
+
+            // Always false for unsigned integers.
+            #[allow(unused_comparisons, clippy::absurd_extreme_comparisons)]
+            const fn is_negative(value: $inner) -> bool { value < 0 }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> From<$inner> for $type {
+            fn from(inner: $inner) -> Self {
+                let value = Self::new_const(inner);
+                invariant_debug_assert!(value);
+                value
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> FromStr for $type {
+            type Err = ParseIntError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from(s.parse::<$inner>()?))
+            }
+        }
+    };
+}
+
+macro_rules! impl_all {
+    ($type:ty, $other:ty, $inner:ty, $uinner:ty) => {
+        impl_create!($type, $inner, $uinner);
+        impl_valid_bounds!($type, $inner);
+        impl_width!($type, $inner, $uinner, MAX);
+
+        impl_arith!($type, $other, $inner, Add, add, |this, other| this
+            + other);
+        impl_arith!($type, $other, $inner, Div, div, |this, other| this
+            / other);
+        impl_arith!($type, $other, $inner, Mul, mul, |this, other| this
+            * other);
+        impl_arith!($type, $other, $inner, Rem, rem, |this, other| this
+            % other);
+        impl_arith!($type, $other, $inner, Sub, sub, |this, other| this
+            - other);
+
+        // Unlike the arithmetic operators, these methods return a new
+        // reflected value rather than the raw result.
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            pub fn reflecting_add(self, rhs: $inner) -> Self {
+                let distance = rhs.abs_diff(0);
+                if Self::is_negative(rhs) {
+                    Self(Self::reflect_down(self.offset(), distance))
+                } else {
+                    Self(Self::reflect_up(self.offset(), distance))
+                }
+            }
+            pub fn reflecting_sub(self, rhs: $inner) -> Self {
+                let distance = rhs.abs_diff(0);
+                if Self::is_negative(rhs) {
+                    Self(Self::reflect_up(self.offset(), distance))
+                } else {
+                    Self(Self::reflect_down(self.offset(), distance))
+                }
+            }
+            pub fn reflecting_mul(self, rhs: $inner) -> Self {
+                if let Some(product) = self.0.checked_mul(rhs) {
+                    return Self::from(product);
+                }
+
+                let (lhs, rhs_abs) = (self.0.abs_diff(0), rhs.abs_diff(0));
+                let mut product = Self::mul_period(lhs, rhs_abs);
+                if Self::is_negative(self.0) != Self::is_negative(rhs) {
+                    product = Self::neg_period(product);
+                }
+
+                // the offset from MIN, modulo two widths
+                let mut min = Self::split(MIN.abs_diff(0));
+                if !Self::is_negative(MIN) {
+                    min = Self::neg_period(min);
+                }
+                match Self::add_period(product, min) {
+                    (rem, false) => Self(MIN.wrapping_add(rem as $inner)),
+                    (rem, true) => Self(MAX.wrapping_sub(rem as $inner)),
+                }
+            }
+            pub fn reflecting_div(self, rhs: $inner) -> Self {
+                match self.0.checked_div(rhs) {
+                    Some(result) => Self::from(result),
+                    None if rhs == 0 => panic!("attempt to divide by zero"),
+                    // only $inner::MIN / -1 overflows, which is -$inner::MIN
+                    None => Self(Self::neg_inner_min()),
+                }
+            }
+            pub fn reflecting_rem(self, rhs: $inner) -> Self {
+                Self::from(self.0.wrapping_rem(rhs))
+            }
+        }
+
+        // The assign operators can overflow (or underflow) the inner type
+        // before they have the chance to reflect, so they go through the
+        // methods above instead.
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            MulAssign,
+            mul_assign,
+            reflecting_mul
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            DivAssign,
+            div_assign,
+            reflecting_div
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            RemAssign,
+            rem_assign,
+            reflecting_rem
+        );
+
+        impl<const MIN: $inner, const MAX: $inner> AddAssign<$inner> for $type {
+            fn add_assign(&mut self, other: $inner) {
+                *self = self.reflecting_add(other)
+            }
+        }
+
+        impl<
+                const MIN: $inner,
+                const MAX: $inner,
+                const OTHER_MIN: $inner,
+                const OTHER_MAX: $inner,
+            > AddAssign<$other> for $type
+        {
+            fn add_assign(&mut self, other: $other) {
+                self.add_assign(other.inner())
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> SubAssign<$inner> for $type {
+            fn sub_assign(&mut self, other: $inner) {
+                *self = self.reflecting_sub(other)
+            }
+        }
+
+        impl<
+                const MIN: $inner,
+                const MAX: $inner,
+                const OTHER_MIN: $inner,
+                const OTHER_MAX: $inner,
+            > SubAssign<$other> for $type
+        {
+            fn sub_assign(&mut self, other: $other) {
+                self.sub_assign(other.inner())
+            }
+        }
+
+        impl_ord!($type, $other, $inner);
+        impl_hash!($type, $inner);
+        impl_bound_consts!($type, $inner);
+        impl_as_float!($type, $inner);
+        impl_default!($type, $inner);
+        impl_display!($type, $inner);
+        impl_deref!($type, $inner);
+        impl_into_inner!($type, $inner);
+        impl_min_max!($type, $inner);
+        impl_apply!($type, $inner, $inner, |value| Self::from(value).0);
+        impl_map!($type, $inner, map, Self, Self::from);
+        impl_clamped!($type, $inner, Self, Self::from);
+        impl_inner_mut!($type, $inner);
+        impl_rebound!($type, $other, $inner, $other, <$other>::from);
+        impl_midpoint!($type, $inner, Self::from);
+        impl_sum!($type, $inner);
+        impl_step!($type, $inner);
+
+        #[cfg(feature = "num-traits")]
+        impl_num_bounded!($type, $inner, Self::from(MIN), Self::from(MAX));
+
+        #[cfg(feature = "serde")]
+        impl_serde!($type, $inner, |inner| Ok(Self::from(inner)));
+    };
+}
+
+// Implementations that only make sense for signed integers.
+macro_rules! impl_signed {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> Neg for $type {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                match self.0.checked_neg() {
+                    Some(inner) => Self::from(inner),
+                    None => Self(Self::neg_inner_min()),
+                }
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// The absolute value, reflected back into range. As with negation,
+            /// the most negative inner value doesn't cause an overflow.
+            pub fn abs(self) -> Self {
+                if self.0 < 0 {
+                    -self
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingU8<const MIN: u8, const MAX: u8>(u8);
+impl_all!(ReflectingU8<MIN, MAX>, ReflectingU8<OTHER_MIN, OTHER_MAX>, u8, u8);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingU16<const MIN: u16, const MAX: u16>(u16);
+impl_all!(ReflectingU16<MIN, MAX>, ReflectingU16<OTHER_MIN, OTHER_MAX>, u16, u16);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingU32<const MIN: u32, const MAX: u32>(u32);
+impl_all!(ReflectingU32<MIN, MAX>, ReflectingU32<OTHER_MIN, OTHER_MAX>, u32, u32);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingU64<const MIN: u64, const MAX: u64>(u64);
+impl_all!(ReflectingU64<MIN, MAX>, ReflectingU64<OTHER_MIN, OTHER_MAX>, u64, u64);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingU128<const MIN: u128, const MAX: u128>(u128);
+impl_all!(
+    ReflectingU128<MIN, MAX>,
+    ReflectingU128<OTHER_MIN, OTHER_MAX>,
+    u128,
+    u128
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingUSize<const MIN: usize, const MAX: usize>(usize);
+impl_all!(
+    ReflectingUSize<MIN, MAX>,
+    ReflectingUSize<OTHER_MIN, OTHER_MAX>,
+    usize,
+    usize
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingI8<const MIN: i8, const MAX: i8>(i8);
+impl_all!(ReflectingI8<MIN, MAX>, ReflectingI8<OTHER_MIN, OTHER_MAX>, i8, u8);
+impl_signed!(ReflectingI8<MIN, MAX>, i8);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingI16<const MIN: i16, const MAX: i16>(i16);
+impl_all!(ReflectingI16<MIN, MAX>, ReflectingI16<OTHER_MIN, OTHER_MAX>, i16, u16);
+impl_signed!(ReflectingI16<MIN, MAX>, i16);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingI32<const MIN: i32, const MAX: i32>(i32);
+impl_all!(ReflectingI32<MIN, MAX>, ReflectingI32<OTHER_MIN, OTHER_MAX>, i32, u32);
+impl_signed!(ReflectingI32<MIN, MAX>, i32);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingI64<const MIN: i64, const MAX: i64>(i64);
+impl_all!(ReflectingI64<MIN, MAX>, ReflectingI64<OTHER_MIN, OTHER_MAX>, i64, u64);
+impl_signed!(ReflectingI64<MIN, MAX>, i64);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingI128<const MIN: i128, const MAX: i128>(i128);
+impl_all!(
+    ReflectingI128<MIN, MAX>,
+    ReflectingI128<OTHER_MIN, OTHER_MAX>,
+    i128,
+    u128
+);
+impl_signed!(ReflectingI128<MIN, MAX>, i128);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReflectingISize<const MIN: isize, const MAX: isize>(isize);
+impl_all!(
+    ReflectingISize<MIN, MAX>,
+    ReflectingISize<OTHER_MIN, OTHER_MAX>,
+    isize,
+    usize
+);
+impl_signed!(ReflectingISize<MIN, MAX>, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Reflects by walking one step at a time, for comparison.
+    fn reference(value: i64, min: i64, max: i64) -> i64 {
+        let (mut current, mut step) = (min, 1);
+        for _ in 0..value.abs_diff(min) {
+            if current + step > max || current + step < min {
+                step = -step;
+            }
+            current += step;
+        }
+        current
+    }
+
+    #[test]
+    fn can_create() {
+        let a = ReflectingU32::<3, 9>(5);
+        assert_eq!(a.inner(), 5)
+    }
+
+    #[test]
+    fn in_range_values_are_unchanged() {
+        for i in 0..=10 {
+            assert_eq!(ReflectingU32::<0, 10>::from(i), i);
+        }
+    }
+
+    #[test]
+    fn range_is_inclusive() {
+        let a = ReflectingU32::<0, 10>::from(10);
+        assert_eq!(a, 10);
+        assert_eq!(a.range(), 0..=10);
+    }
+
+    #[test]
+    fn reflects_off_max() {
+        assert_eq!(ReflectingU32::<0, 10>::from(13), 7);
+        assert_eq!(ReflectingU32::<0, 10>::from(20), 0);
+    }
+
+    #[test]
+    fn reflects_off_both_bounds() {
+        // 25 -> 10 -> 0 -> 5
+        assert_eq!(ReflectingU32::<0, 10>::from(25), 5);
+        // 38 -> 10 -> 0 -> 10 -> 2
+        assert_eq!(ReflectingU32::<0, 10>::from(38), 2);
+        assert_eq!(ReflectingU32::<0, 10>::from(1000003), 3);
+    }
+
+    #[test]
+    fn reflects_off_min() {
+        assert_eq!(ReflectingU32::<5, 9>::from(3), 7);
+        assert_eq!(ReflectingU32::<5, 9>::from(0), 8);
+        assert_eq!(ReflectingI32::<-10, 10>::from(-13), -7);
+        // -35 -> -10 -> 10 -> 5
+        assert_eq!(ReflectingI32::<-10, 10>::from(-35), 5);
+    }
+
+    #[test]
+    fn matches_reference() {
+        for value in -60..60 {
+            let expected = reference(value, -7, 4);
+            assert_eq!(ReflectingI64::<-7, 4>::from(value), expected);
+
+            let expected = reference(value, 3, 8);
+            assert_eq!(ReflectingI64::<3, 8>::from(value), expected);
+        }
+    }
+
+    #[test]
+    fn addassign_reflects() {
+        let mut a = ReflectingU32::<0, 10>(4);
+        assert_eq!(a + 9, 13);
+
+        a += 9;
+        assert_eq!(a, 7);
+        a += 5;
+        assert_eq!(a, 8);
+        a += 23;
+        assert_eq!(a, 9);
+    }
+
+    #[test]
+    fn subassign_reflects() {
+        let mut a = ReflectingU32::<0, 10>(4);
+        a -= 7;
+        assert_eq!(a, 3);
+        a -= 15;
+        assert_eq!(a, 8);
+
+        let mut b = ReflectingU32::<4, 8>(6);
+        b -= 4000001;
+        assert_eq!(b, ReflectingU32::<4, 8>::from(4000003));
+    }
+
+    #[test]
+    fn negative_operands_reflect() {
+        let mut a = ReflectingI32::<-10, 10>(5);
+        a += -20;
+        assert_eq!(a, -5);
+        a -= -22;
+        assert_eq!(a, 3);
+    }
+
+    #[test]
+    fn assign_matches_new() {
+        for start in -7..=4 {
+            for rhs in -60..60 {
+                let mut a = ReflectingI64::<-7, 4>::from(start);
+                a += rhs;
+                assert_eq!(a, ReflectingI64::<-7, 4>::from(start + rhs));
+
+                let mut a = ReflectingI64::<-7, 4>::from(start);
+                a -= rhs;
+                assert_eq!(a, ReflectingI64::<-7, 4>::from(start - rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn can_addassign_other() {
+        let mut a = ReflectingU32::<3, 9>(3);
+        let b = ReflectingU32::<0, 10>(8);
+        a += b;
+        assert_eq!(a, 7);
+        a -= b;
+        assert_eq!(a, 7);
+    }
+
+    #[test]
+    fn mulassign_reflects() {
+        let mut a = ReflectingU32::<0, 10>(4);
+        a *= 3;
+        assert_eq!(a, 8);
+    }
+
+    #[test]
+    fn full_range_does_not_overflow() {
+        let mut a = ReflectingU8::<0, 255>(250);
+        a += 10;
+        assert_eq!(a, 250);
+        a -= 255;
+        assert_eq!(a, 5);
+
+        let mut b = ReflectingI8::<-128, 127>(120);
+        b += 100;
+        assert_eq!(b, 34);
+        b -= 127;
+        assert_eq!(b, -93);
+        b += -100;
+        assert_eq!(b, -63);
+
+        let mut c = ReflectingI8::<-128, 127>(-128);
+        assert_eq!(-c, 126);
+        assert_eq!(c.reflecting_div(-1), 126);
+        assert_eq!(c.reflecting_rem(-1), 0);
+        assert_eq!(ReflectingI8::<-128, 0>(-128).reflecting_div(-1), -128);
+        c /= -1;
+        assert_eq!(c, 126);
+        c = ReflectingI8::<-128, 127>(-128);
+        c %= -1;
+        assert_eq!(c, 0);
+
+        let mut d = ReflectingU128::<0, { u128::MAX }>(u128::MAX - 1);
+        d += 3;
+        assert_eq!(d, u128::MAX - 2);
+    }
+
+    #[test]
+    fn test_neg() {
+        let foo = ReflectingI32::<-5, 10>::from(8);
+        assert_eq!(-foo, -2);
+        let foo = ReflectingI32::<-5, 10>::from(-3);
+        assert_eq!(-foo, 3);
+    }
+
+    #[test]
+    fn ord_is_implemented() {
+        let a = ReflectingU32::<0, 8>(5);
+        let b = ReflectingU32::<5, 20>(10);
+        assert!(a < b);
+        assert!(a < 15);
+    }
+
+    #[test]
+    fn all_types_exist() {
+        let _ = ReflectingU8::<0, 10>::from(5);
+        let _ = ReflectingU16::<0, 10>::from(5);
+        let _ = ReflectingU32::<0, 10>::from(5);
+        let _ = ReflectingU64::<0, 10>::from(5);
+        let _ = ReflectingU128::<0, 10>::from(5);
+        let _ = ReflectingUSize::<0, 10>::from(5);
+
+        let _ = ReflectingI8::<-10, 10>::from(-5);
+        let _ = ReflectingI16::<-10, 10>::from(-5);
+        let _ = ReflectingI32::<-10, 10>::from(-5);
+        let _ = ReflectingI64::<-10, 10>::from(-5);
+        let _ = ReflectingI128::<-10, 10>::from(-5);
+        let _ = ReflectingISize::<-10, 10>::from(-5);
+    }
+
+    #[test]
+    fn test_parse() {
+        let foo: ReflectingU32<0, 10> = "13".parse().unwrap();
+        assert_eq!(foo, 7);
+        assert!("foo".parse::<ReflectingU32<0, 10>>().is_err());
+    }
+
+    #[test]
+    fn test_default_and_display() {
+        let foo = ReflectingI32::<-5, 10>::default();
+        assert_eq!(foo, -5);
+        assert_eq!(format!("{}", ReflectingU32::<0, 10>::from(13)), "7");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let foo = ReflectingU32::<0, 10>::from(7);
+        assert_eq!(serde_json::to_string(&foo).unwrap(), "7");
+        let bar: ReflectingU32<0, 10> = serde_json::from_str("13").unwrap();
+        assert_eq!(bar, 7);
+    }
+
+    #[test]
+    fn is_at_bound() {
+        let a = ReflectingU32::<0, 10>::clamp(30);
+        assert!(a.is_at_max());
+        assert!(!a.is_at_min());
+        assert!(ReflectingU32::<0, 10>::clamp(20).is_at_min());
+    }
+
+    #[test]
+    fn contains() {
+        assert!(ReflectingI32::<-2, 8>::contains(-2));
+        assert!(ReflectingI32::<-2, 8>::contains(8));
+        assert!(!ReflectingI32::<-2, 8>::contains(9));
+        assert!(!ReflectingI32::<-2, 8>::contains(-3));
+    }
+
+    #[test]
+    fn inner_mut() {
+        let mut a = ReflectingU8::<0, 10>::from(8);
+        {
+            let mut inner = a.inner_mut();
+            *inner += 5;
+            assert_eq!(*inner, 13);
+        }
+        assert_eq!(a, 7);
+    }
+
+    #[test]
+    fn set() {
+        let mut a = ReflectingU8::<0, 10>::from(3);
+        a.set(13);
+        assert_eq!(a, 7);
+    }
+
+    #[test]
+    fn rebound() {
+        let a = ReflectingU32::<0, 1000>::from(500);
+        assert_eq!(a.rebound::<0, 300>(), 100);
+        assert_eq!(a.rebound::<400, 2000>(), 500);
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(ReflectingI32::<-10, 5>::from(-3).abs(), 3);
+        assert_eq!(ReflectingI32::<-10, 5>::from(-8).abs(), 2);
+        assert_eq!(ReflectingI8::<-128, 127>::from(-128).abs(), 126);
+
+        // 128 bounces off of MAX, rather than only moving upwards
+        assert_eq!(ReflectingI8::<-128, 0>::from(-128).abs(), -128);
+        assert_eq!(-ReflectingI8::<-128, 0>::from(-128), -128);
+        assert_eq!(ReflectingI8::<-128, -100>::from(-128).abs(), -104);
+        assert_eq!(-ReflectingI16::<{ i16::MIN }, 10>::from(i16::MIN), -32748);
+    }
+
+    #[test]
+    fn mul_near_the_inner_maximum() {
+        let mut a = ReflectingU8::<0, 10>::from(9);
+        a *= 100;
+        assert_eq!(a, 0);
+        assert_eq!(ReflectingU8::<0, 200>::from(150).reflecting_mul(2), 100);
+        assert_eq!(ReflectingU8::<0, 255>::from(255).reflecting_mul(2), 0);
+
+        // reference implementation using wider arithmetic
+        for lhs in 3..=200u8 {
+            for rhs in 0..=u8::MAX {
+                let expected = ReflectingU32::<3, 200>::from(
+                    lhs as u32 * rhs as u32,
+                );
+                let mut actual = ReflectingU8::<3, 200>::from(lhs);
+                actual *= rhs;
+                assert_eq!(actual, expected.inner() as u8);
+            }
+        }
+        for lhs in -100..=50i8 {
+            for rhs in i8::MIN..=i8::MAX {
+                let expected = ReflectingI32::<-100, 50>::from(
+                    lhs as i32 * rhs as i32,
+                );
+                let actual = ReflectingI8::<-100, 50>::from(lhs);
+                assert_eq!(actual.reflecting_mul(rhs), expected.inner() as i8);
+            }
+        }
+        for lhs in i8::MIN..=i8::MAX {
+            let expected = ReflectingI32::<{ i8::MIN as i32 }, 127>::from(
+                lhs as i32 * -128,
+            );
+            let actual = ReflectingI8::<{ i8::MIN }, 127>::from(lhs);
+            assert_eq!(actual.reflecting_mul(-128), expected.inner() as i8);
+        }
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(ReflectingI8::<-128, 127>::midpoint(), -1);
+        assert_eq!(ReflectingU32::<10, 20>::midpoint(), 15);
+    }
+
+    #[test]
+    fn map() {
+        let a = ReflectingU32::<2, 8>::from(6);
+        assert_eq!(a.map(|x| x * 2), 4);
+    }
+
+    #[test]
+    fn apply() {
+        assert_eq!(ReflectingU32::<0, 10>::apply(13), 7);
+        assert_eq!(ReflectingU32::<0, 10>::apply(10), 10);
+        assert_eq!(ReflectingI8::<-10, 10>::apply(-12), -8);
+    }
+
+    #[test]
+    fn test_all() {
+        let all: Vec<_> = ReflectingI32::<-1, 1>::all().collect();
+        assert_eq!(all, [-1, 0, 1]);
+        assert_eq!(ReflectingI32::<-1, 1>::all().len(), 3);
+    }
+
+    #[test]
+    fn test_sum() {
+        let sum: ReflectingU32<0, 10> = [4, 5, 4].into_iter().sum();
+        assert_eq!(sum, 7);
+        let product: ReflectingU32<0, 10> = [2, 3, 2].into_iter().product();
+        assert_eq!(product, 8);
+    }
+}
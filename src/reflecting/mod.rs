@@ -0,0 +1,19 @@
+//! Types that reflect off their bounds to fit within them.
+//!
+//! Rather than wrapping around or sticking to a bound, values that overshoot
+//! are "bounced" back into range by however far they went past it. Both
+//! bounds are inclusive.
+//!
+//! ```rust
+//! use clamps::reflecting::ReflectingU32;
+//!
+//! // 13 is 3 past MAX, so it reflects back to 10 - 3
+//! let foo = ReflectingU32::<0, 10>::from(13);
+//! assert_eq!(foo, 7);
+//! ```
+
+mod generic;
+mod int;
+
+pub use generic::*;
+pub use int::*;
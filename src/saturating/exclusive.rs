@@ -0,0 +1,359 @@
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::ParseIntError;
+use core::ops::{
+    Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Neg, Range, Rem,
+    RemAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+use crate::clamped::Clamped;
+use crate::macros::*;
+
+// Like the saturating types, but with an exclusive MAX (MIN..MAX), so values
+// that are too large are pinned to MAX - 1. This matches the half-open ranges
+// used by the wrapping and bounded types.
+macro_rules! impl_all {
+    ($type:ty, $other:ty, $inner:ty, $uinner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            pub fn new(inner: $inner) -> Self { Self::from(inner) }
+            pub fn inner(&self) -> $inner { self.0 }
+
+            /// A `const` version of [`Self::new`], for use in `const` and
+            /// `static` items.
+            pub const fn new_const(inner: $inner) -> Self {
+                let () = Self::VALID_BOUNDS;
+
+                if inner >= MAX {
+                    Self(MAX - 1)
+                } else if inner < MIN {
+                    Self(MIN)
+                } else {
+                    Self(inner)
+                }
+            }
+
+            /// A `const` version of [`Self::inner`].
+            pub const fn inner_const(&self) -> $inner { self.0 }
+            pub fn range(&self) -> Range<$inner> { MIN..MAX }
+            pub fn min_bound(&self) -> $inner { MIN }
+            pub fn max_bound(&self) -> $inner { MAX }
+
+            pub fn is_at_min(&self) -> bool { self.0 == MIN }
+            pub fn is_at_max(&self) -> bool { self.0 == MAX - 1 }
+
+            /// Whether `inner` is already in range, and so would be left
+            /// unchanged by [`From::from`].
+            pub fn contains(inner: $inner) -> bool {
+                inner >= MIN && inner < MAX
+            }
+
+            /// An alias for [`From::from`].
+            pub fn clamp(inner: $inner) -> Self { Self::from(inner) }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> From<$inner> for $type {
+            fn from(inner: $inner) -> Self {
+                let value = Self::new_const(inner);
+                invariant_debug_assert!(value);
+                value
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> FromStr for $type {
+            type Err = ParseIntError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from(s.parse::<$inner>()?))
+            }
+        }
+
+        // As with the saturating types, these return a new saturated value
+        // rather than the raw result, and can never overflow.
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            pub fn saturating_add(self, rhs: $inner) -> Self {
+                strict_debug_assert!(
+                    self.0.checked_add(rhs),
+                    "saturated",
+                    self.0,
+                    "+",
+                    rhs,
+                    ".."
+                );
+                Self::from(self.0.saturating_add(rhs))
+            }
+            pub fn saturating_sub(self, rhs: $inner) -> Self {
+                strict_debug_assert!(
+                    self.0.checked_sub(rhs),
+                    "saturated",
+                    self.0,
+                    "-",
+                    rhs,
+                    ".."
+                );
+                Self::from(self.0.saturating_sub(rhs))
+            }
+            pub fn saturating_mul(self, rhs: $inner) -> Self {
+                strict_debug_assert!(
+                    self.0.checked_mul(rhs),
+                    "saturated",
+                    self.0,
+                    "*",
+                    rhs,
+                    ".."
+                );
+                Self::from(self.0.saturating_mul(rhs))
+            }
+            pub fn saturating_div(self, rhs: $inner) -> Self {
+                // dividing by zero panics here, before it can be mistaken
+                // for saturating below
+                let result = self.0.saturating_div(rhs);
+                strict_debug_assert!(
+                    self.0.checked_div(rhs),
+                    "saturated",
+                    self.0,
+                    "/",
+                    rhs,
+                    ".."
+                );
+                Self::from(result)
+            }
+        }
+
+        impl_arith!($type, $other, $inner, Add, add, |this, other| this
+            + other);
+        impl_arith!($type, $other, $inner, Div, div, |this, other| this
+            / other);
+        impl_arith!($type, $other, $inner, Mul, mul, |this, other| this
+            * other);
+        impl_arith!($type, $other, $inner, Rem, rem, |this, other| this
+            % other);
+        impl_arith!($type, $other, $inner, Sub, sub, |this, other| this
+            - other);
+
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            AddAssign,
+            add_assign,
+            saturating_add
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            SubAssign,
+            sub_assign,
+            saturating_sub
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            MulAssign,
+            mul_assign,
+            saturating_mul
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            DivAssign,
+            div_assign,
+            saturating_div
+        );
+        impl_arith_assign!($type, $other, $inner, RemAssign, rem_assign, rem);
+
+        impl_valid_bounds!($type, $inner);
+        impl_width!($type, $inner, $uinner, MAX - 1);
+        impl_ord!($type, $other, $inner);
+        impl_hash!($type, $inner);
+        impl_bound_consts!($type, $inner);
+        impl_as_float!($type, $inner);
+        impl_default!($type, $inner);
+        impl_display!($type, $inner);
+        impl_deref!($type, $inner);
+        impl_into_inner!($type, $inner);
+        impl_min_max!($type, $inner);
+        impl_apply!($type, $inner, $inner, |value| Self::from(value).0);
+        impl_map!($type, $inner, map, Self, Self::from);
+        impl_clamped!($type, $inner, Self, Self::from);
+
+        #[cfg(feature = "num-traits")]
+        impl_num_bounded!($type, $inner, Self::from(MIN), Self::from(MAX));
+
+        #[cfg(feature = "serde")]
+        impl_serde!($type, $inner, |inner| Ok(Self::from(inner)));
+    };
+}
+
+// Implementations that only make sense for signed integers.
+macro_rules! impl_signed {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> Neg for $type {
+            type Output = Self;
+            fn neg(self) -> Self::Output { Self::from(self.0.saturating_neg()) }
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveU8<const MIN: u8, const MAX: u8>(u8);
+impl_all!(
+    SaturatingExclusiveU8<MIN, MAX>,
+    SaturatingExclusiveU8<OTHER_MIN, OTHER_MAX>,
+    u8,
+    u8
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveU16<const MIN: u16, const MAX: u16>(u16);
+impl_all!(
+    SaturatingExclusiveU16<MIN, MAX>,
+    SaturatingExclusiveU16<OTHER_MIN, OTHER_MAX>,
+    u16,
+    u16
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveU32<const MIN: u32, const MAX: u32>(u32);
+impl_all!(
+    SaturatingExclusiveU32<MIN, MAX>,
+    SaturatingExclusiveU32<OTHER_MIN, OTHER_MAX>,
+    u32,
+    u32
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveU64<const MIN: u64, const MAX: u64>(u64);
+impl_all!(
+    SaturatingExclusiveU64<MIN, MAX>,
+    SaturatingExclusiveU64<OTHER_MIN, OTHER_MAX>,
+    u64,
+    u64
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveU128<const MIN: u128, const MAX: u128>(u128);
+impl_all!(
+    SaturatingExclusiveU128<MIN, MAX>,
+    SaturatingExclusiveU128<OTHER_MIN, OTHER_MAX>,
+    u128,
+    u128
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveUSize<const MIN: usize, const MAX: usize>(usize);
+impl_all!(
+    SaturatingExclusiveUSize<MIN, MAX>,
+    SaturatingExclusiveUSize<OTHER_MIN, OTHER_MAX>,
+    usize,
+    usize
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveI8<const MIN: i8, const MAX: i8>(i8);
+impl_all!(
+    SaturatingExclusiveI8<MIN, MAX>,
+    SaturatingExclusiveI8<OTHER_MIN, OTHER_MAX>,
+    i8,
+    u8
+);
+impl_signed!(SaturatingExclusiveI8<MIN, MAX>, i8);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveI16<const MIN: i16, const MAX: i16>(i16);
+impl_all!(
+    SaturatingExclusiveI16<MIN, MAX>,
+    SaturatingExclusiveI16<OTHER_MIN, OTHER_MAX>,
+    i16,
+    u16
+);
+impl_signed!(SaturatingExclusiveI16<MIN, MAX>, i16);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveI32<const MIN: i32, const MAX: i32>(i32);
+impl_all!(
+    SaturatingExclusiveI32<MIN, MAX>,
+    SaturatingExclusiveI32<OTHER_MIN, OTHER_MAX>,
+    i32,
+    u32
+);
+impl_signed!(SaturatingExclusiveI32<MIN, MAX>, i32);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveI64<const MIN: i64, const MAX: i64>(i64);
+impl_all!(
+    SaturatingExclusiveI64<MIN, MAX>,
+    SaturatingExclusiveI64<OTHER_MIN, OTHER_MAX>,
+    i64,
+    u64
+);
+impl_signed!(SaturatingExclusiveI64<MIN, MAX>, i64);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveI128<const MIN: i128, const MAX: i128>(i128);
+impl_all!(
+    SaturatingExclusiveI128<MIN, MAX>,
+    SaturatingExclusiveI128<OTHER_MIN, OTHER_MAX>,
+    i128,
+    u128
+);
+impl_signed!(SaturatingExclusiveI128<MIN, MAX>, i128);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SaturatingExclusiveISize<const MIN: isize, const MAX: isize>(isize);
+impl_all!(
+    SaturatingExclusiveISize<MIN, MAX>,
+    SaturatingExclusiveISize<OTHER_MIN, OTHER_MAX>,
+    isize,
+    usize
+);
+impl_signed!(SaturatingExclusiveISize<MIN, MAX>, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_saturates_below_max() {
+        type Index = SaturatingExclusiveUSize<0, 3>;
+        assert_eq!(Index::from(3), 2);
+        assert_eq!(Index::from(100), 2);
+        assert_eq!(Index::from(1), 1);
+        assert!(Index::from(7).is_at_max());
+        assert_eq!(Index::from(1).range(), 0..3);
+        assert_eq!(Index::width(), 3);
+        assert_eq!(Index::cardinality(), 3);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn arithmetic_saturates_below_max() {
+        let mut a = SaturatingExclusiveI8::<-10, 10>::from(5);
+        assert_eq!(a + 10, 15);
+        assert_eq!(a.saturating_add(10), 9);
+        a += 4;
+        assert_eq!(a, 9);
+        a += 1;
+        assert_eq!(a, 9);
+        a -= 100;
+        assert_eq!(a, -10);
+        assert_eq!(-a, 9);
+
+        type Full = SaturatingExclusiveU8<0, { u8::MAX }>;
+        assert_eq!(Full::from(250).saturating_add(100), 254);
+    }
+
+    #[test]
+    fn contains_is_half_open() {
+        type Foo = SaturatingExclusiveU32<2, 8>;
+        assert!(Foo::contains(2));
+        assert!(Foo::contains(7));
+        assert!(!Foo::contains(8));
+        assert!(!Foo::contains(1));
+        assert_eq!(Foo::apply(8), 7);
+        assert_eq!(Foo::MAX_BOUND, 8);
+    }
+}
@@ -0,0 +1,157 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, RangeInclusive, Rem,
+    RemAssign, Sub, SubAssign,
+};
+
+use crate::clamped::Clamped;
+use crate::float::FloatBounds;
+use crate::macros::*;
+
+macro_rules! impl_all {
+    ($type:ident, $inner:ty) => {
+        impl<B: FloatBounds<$inner>> $type<B> {
+            pub fn new(inner: $inner) -> Self { Self::from(inner) }
+            pub fn range(&self) -> RangeInclusive<$inner> { B::MIN..=B::MAX }
+        }
+
+        impl<B: FloatBounds<$inner>> From<$inner> for $type<B> {
+            /// NaN saturates to `MIN`.
+            fn from(inner: $inner) -> Self {
+                assert!(B::MIN < B::MAX, "MIN must be less than MAX");
+
+                if inner.is_nan() || inner < B::MIN {
+                    Self(B::MIN, PhantomData)
+                } else if inner > B::MAX {
+                    Self(B::MAX, PhantomData)
+                } else {
+                    Self(inner, PhantomData)
+                }
+            }
+        }
+
+        impl<B: FloatBounds<$inner>> Neg for $type<B> {
+            type Output = Self;
+            fn neg(self) -> Self::Output { Self::from(-self.0) }
+        }
+
+        impl_float!($type, $inner);
+        impl_clamped_float!($type, $inner, Self, Self::from);
+        impl_float_arith_assign!($type, $inner);
+
+        #[cfg(feature = "serde")]
+        impl_serde_float!($type, $inner, |inner| Ok(Self::from(inner)));
+    };
+}
+
+pub struct SaturatingF32<B>(f32, PhantomData<B>);
+impl_all!(SaturatingF32, f32);
+
+pub struct SaturatingF64<B>(f64, PhantomData<B>);
+impl_all!(SaturatingF64, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Unit;
+    impl FloatBounds<f32> for Unit {
+        const MIN: f32 = 0.0;
+        const MAX: f32 = 1.0;
+    }
+    impl FloatBounds<f64> for Unit {
+        const MIN: f64 = 0.0;
+        const MAX: f64 = 1.0;
+    }
+
+    struct Audio;
+    impl FloatBounds<f64> for Audio {
+        const MIN: f64 = -1.0;
+        const MAX: f64 = 1.0;
+    }
+
+    struct Invalid;
+    impl FloatBounds<f32> for Invalid {
+        const MIN: f32 = 1.0;
+        const MAX: f32 = 1.0;
+    }
+
+    #[test]
+    fn can_create() {
+        let foo = SaturatingF32::<Unit>::from(0.5);
+        assert_eq!(foo.inner(), 0.5);
+        assert_eq!(foo.range(), 0.0..=1.0);
+        assert_eq!(foo.min_bound(), 0.0);
+        assert_eq!(foo.max_bound(), 1.0);
+    }
+
+    #[test]
+    fn will_saturate() {
+        assert_eq!(SaturatingF32::<Unit>::from(1.5), 1.0);
+        assert_eq!(SaturatingF32::<Unit>::from(-0.5), 0.0);
+        assert_eq!(SaturatingF64::<Audio>::from(f64::INFINITY), 1.0);
+        assert_eq!(SaturatingF64::<Audio>::from(f64::NEG_INFINITY), -1.0);
+    }
+
+    #[test]
+    fn nan_saturates_to_min() {
+        assert_eq!(SaturatingF32::<Unit>::from(f32::NAN), 0.0);
+        assert_eq!(SaturatingF64::<Audio>::from(f64::NAN), -1.0);
+
+        let mut foo = SaturatingF64::<Audio>::from(0.5);
+        foo *= f64::NAN;
+        assert_eq!(foo, -1.0);
+    }
+
+    #[test]
+    fn test_arith() {
+        let mut foo = SaturatingF64::<Audio>::from(0.5);
+        assert_eq!(foo + 1.0, 1.5);
+        assert_eq!(foo * 4.0, 2.0);
+
+        foo += 1.0;
+        assert_eq!(foo, 1.0);
+        foo -= 0.25;
+        assert_eq!(foo, 0.75);
+        foo *= -4.0;
+        assert_eq!(foo, -1.0);
+        assert_eq!(-foo, 1.0);
+
+        let bar = SaturatingF64::<Unit>::from(0.25);
+        foo += bar;
+        assert_eq!(foo, -0.75);
+    }
+
+    #[test]
+    fn test_ord() {
+        let foo = SaturatingF64::<Audio>::from(-0.5);
+        let bar = SaturatingF64::<Unit>::from(0.5);
+        assert!(foo < bar);
+        assert!(bar > 0.0);
+    }
+
+    #[test]
+    fn test_default_and_display() {
+        assert_eq!(SaturatingF64::<Audio>::default(), -1.0);
+        let foo = SaturatingF32::<Unit>::from(0.5);
+        assert_eq!(format!("{}", foo), "0.5");
+        assert_eq!(format!("{:?}", foo), "SaturatingF32(0.5)");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let foo = SaturatingF64::<Unit>::from(0.5);
+        assert_eq!(serde_json::to_string(&foo).unwrap(), "0.5");
+        let bar: SaturatingF64<Unit> = serde_json::from_str("1.5").unwrap();
+        assert_eq!(bar, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_use_invalid_bounds() {
+        let _ = SaturatingF32::<Invalid>::from(1.0);
+    }
+}
@@ -1,35 +1,122 @@
-use std::fmt;
-use std::ops::{
-    Add, AddAssign, Div, DivAssign, Mul, MulAssign, RangeInclusive, Rem,
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, RangeInclusive, Rem,
     RemAssign, Sub, SubAssign,
 };
 
+use crate::bounded::BoundsError;
+use crate::bounds::Bounds;
+use crate::builder::Builder;
+use crate::clamped::Clamped;
+
 pub struct Saturating<T> {
-    inner: T,
-    min:   T,
-    max:   T,
+    inner:  T,
+    bounds: Bounds<T>,
 }
 
 impl<T: PartialOrd + Clone> Saturating<T> {
-    pub fn new(mut inner: T, min: T, max: T) -> Self {
-        if min >= max {
-            panic!("MIN value must be less than MAX")
+    /// Panics if `min` is not less than `max`. See [`Saturating::try_new`] for
+    /// a fallible alternative.
+    pub fn new(inner: T, min: T, max: T) -> Self {
+        match Self::try_new(inner, min, max) {
+            Ok(saturating) => saturating,
+            Err(_) => panic!("MIN value must be less than MAX"),
         }
+    }
+
+    pub fn try_new(inner: T, min: T, max: T) -> Result<Self, BoundsError> {
+        Ok(Self::with_bounds(inner, Bounds::try_new(min, max)?))
+    }
 
-        if inner >= max {
-            inner = max.clone()
-        } else if inner < min {
-            inner = min.clone()
+    /// Takes the bounds from `min..=max`. A range containing fewer than two
+    /// values is rejected with [`BoundsError::InvalidBounds`].
+    pub fn from_range(
+        inner: T, range: RangeInclusive<T>,
+    ) -> Result<Self, BoundsError> {
+        let (min, max) = range.into_inner();
+        Self::try_new(inner, min, max)
+    }
+
+    pub fn with_bounds(mut inner: T, bounds: Bounds<T>) -> Self {
+        if inner >= bounds.max {
+            inner = bounds.max.clone()
+        } else if inner < bounds.min {
+            inner = bounds.min.clone()
         }
 
-        Self { inner, max, min }
+        Self { inner, bounds }
+    }
+
+    /// Returns a [`Builder`], which names each argument explicitly.
+    pub fn builder() -> Builder<T, Self> {
+        Builder::new(|inner, bounds| Ok(Self::with_bounds(inner, bounds)))
     }
 
     pub fn inner(&self) -> &T { &self.inner }
     pub fn into_inner(self) -> T { self.inner }
-    pub fn range(&self) -> RangeInclusive<&T> { &self.min..=&self.max }
-    pub fn min_bound(&self) -> &T { &self.min }
-    pub fn max_bound(&self) -> &T { &self.max }
+    pub fn bounds(&self) -> &Bounds<T> { &self.bounds }
+    pub fn contains(&self, value: &T) -> bool {
+        self.bounds.contains_inclusive(value)
+    }
+    pub fn range(&self) -> RangeInclusive<&T> { self.bounds.range_inclusive() }
+    pub fn min_bound(&self) -> &T { &self.bounds.min }
+    pub fn max_bound(&self) -> &T { &self.bounds.max }
+}
+
+impl Saturating<usize> {
+    /// An index into `slice`, saturated into `0..=slice.len() - 1`.
+    ///
+    /// A slice with a single element would need the bounds `0..=0`, but as
+    /// with [`Saturating::from_range`], `min` must be less than `max`. So
+    /// slices with fewer than two elements are rejected with
+    /// [`BoundsError::InvalidBounds`].
+    pub fn for_slice<E>(
+        index: usize, slice: &[E],
+    ) -> Result<Self, BoundsError> {
+        match slice.len().checked_sub(1) {
+            Some(max) => Self::try_new(index, 0, max),
+            None => Err(BoundsError::InvalidBounds),
+        }
+    }
+}
+
+// Each value keeps its own bounds, so these return whichever value is chosen
+// as-is, along with its bounds
+impl<T: PartialOrd> Saturating<T> {
+    /// Returns whichever value is larger, or `other` if they're equal.
+    pub fn max_with(self, other: Self) -> Self {
+        if self.inner > other.inner {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns whichever value is smaller, or `self` if they're equal.
+    pub fn min_with(self, other: Self) -> Self {
+        if other.inner < self.inner {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone> Clamped for Saturating<T> {
+    type Inner = T;
+    type Output = Self;
+
+    fn inner(&self) -> T { self.inner.clone() }
+    fn min_bound(&self) -> T { self.bounds.min.clone() }
+    fn max_bound(&self) -> T { self.bounds.max.clone() }
+    fn clamp(&self, value: T) -> Self {
+        Saturating::with_bounds(value, self.bounds.clone())
+    }
+}
+
+impl<T: PartialOrd + Clone> crate::Clamp<T> for Saturating<T> {
+    fn clamp(&self, value: T) -> T { Clamped::clamp(self, value).inner }
 }
 
 //arithmetic
@@ -55,48 +142,114 @@ impl_arith!(Mul, mul, |this, other| this * other);
 impl_arith!(Div, div, |this, other| this / other);
 impl_arith!(Rem, rem, |this, other| this % other);
 
-// assigning arithmetic
+// assigning arithmetic, which only needs the one operator (so that types like
+// Duration, which don't implement Rem, can still be used)
 macro_rules! impl_arith_assign {
-    ($trait:ident, $fn:ident, $impl:expr) => {
-        impl<
-                T: PartialOrd
-                    + Copy
-                    + Add<Output = T>
-                    + Sub<Output = T>
-                    + Mul<Output = T>
-                    + Div<Output = T>
-                    + Rem<Output = T>
-                    + $trait,
-            > $trait<T> for Saturating<T>
+    ($trait:ident, $fn:ident, $op:ident, $impl:expr) => {
+        impl<T: PartialOrd + Copy + $op<Output = T>> $trait<T>
+            for Saturating<T>
         {
             fn $fn(&mut self, other: T) {
-                *self = Saturating::new($impl(*self, other), self.min, self.max)
+                let result = $impl(*self, other);
+                *self = Saturating::with_bounds(result, self.bounds)
             }
         }
 
-        impl<
-                T: PartialOrd
-                    + Copy
-                    + Add<Output = T>
-                    + Sub<Output = T>
-                    + Mul<Output = T>
-                    + Div<Output = T>
-                    + Rem<Output = T>
-                    + $trait,
-            > $trait<Saturating<T>> for Saturating<T>
+        impl<T: PartialOrd + Copy + $op<Output = T>> $trait<Saturating<T>>
+            for Saturating<T>
         {
             fn $fn(&mut self, other: Saturating<T>) {
-                *self = Saturating::new($impl(*self, other), self.min, self.max)
+                let result = $impl(*self, other);
+                *self = Saturating::with_bounds(result, self.bounds)
             }
         }
     };
 }
 
-impl_arith_assign!(AddAssign, add_assign, |this, other| this + other);
-impl_arith_assign!(SubAssign, sub_assign, |this, other| this - other);
-impl_arith_assign!(MulAssign, mul_assign, |this, other| this * other);
-impl_arith_assign!(DivAssign, div_assign, |this, other| this / other);
-impl_arith_assign!(RemAssign, rem_assign, |this, other| this % other);
+impl_arith_assign!(AddAssign, add_assign, Add, |this, other| this + other);
+impl_arith_assign!(MulAssign, mul_assign, Mul, |this, other| this * other);
+impl_arith_assign!(DivAssign, div_assign, Div, |this, other| this / other);
+impl_arith_assign!(RemAssign, rem_assign, Rem, |this, other| this % other);
+
+fn saturating_sub<T: Copy + PartialOrd + Sub<Output = T>>(
+    this: Saturating<T>, other: T,
+) -> T {
+    // if we'd go below MIN, there's a chance of underflowing before we get
+    // the chance to saturate (e.g. for Duration), so it's handled in advance
+    let offset = this.inner - this.bounds.min;
+    if other > offset {
+        return this.bounds.min;
+    }
+    this.inner - other
+}
+
+impl<T: PartialOrd + Copy + Sub<Output = T>> SubAssign<T> for Saturating<T> {
+    fn sub_assign(&mut self, other: T) {
+        let result = saturating_sub(*self, other);
+        *self = Saturating::with_bounds(result, self.bounds)
+    }
+}
+
+impl<T: PartialOrd + Copy + Sub<Output = T>> SubAssign<Saturating<T>>
+    for Saturating<T>
+{
+    fn sub_assign(&mut self, other: Saturating<T>) {
+        self.sub_assign(other.inner)
+    }
+}
+
+// Like the assigning operators, but returning a new value with the same bounds
+// rather than modifying this one
+impl<
+        T: PartialOrd
+            + Copy
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>,
+    > Saturating<T>
+{
+    /// Multiplies by `factor`, saturating the result into the same bounds.
+    pub fn scaled(&self, factor: T) -> Self {
+        Saturating::with_bounds(self.inner * factor, self.bounds)
+    }
+
+    /// Adds `delta`, saturating the result into the same bounds.
+    pub fn offset(&self, delta: T) -> Self {
+        Saturating::with_bounds(self.inner + delta, self.bounds)
+    }
+
+    /// Subtracts `delta`, saturating the result into the same bounds.
+    pub fn shifted_down(&self, delta: T) -> Self {
+        Saturating::with_bounds(saturating_sub(*self, delta), self.bounds)
+    }
+}
+
+// The same names as the concrete types' methods. Most of these are the same as
+// the methods above, but take self by value.
+impl<
+        T: PartialOrd
+            + Copy
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    > Saturating<T>
+{
+    pub fn saturating_add(self, rhs: T) -> Self { self.offset(rhs) }
+    pub fn saturating_sub(self, rhs: T) -> Self { self.shifted_down(rhs) }
+    pub fn saturating_mul(self, rhs: T) -> Self { self.scaled(rhs) }
+    pub fn saturating_div(self, rhs: T) -> Self {
+        Saturating::with_bounds(self.inner / rhs, self.bounds)
+    }
+}
+
+// negation
+impl<T: PartialOrd + Clone + Neg<Output = T>> Neg for Saturating<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Saturating::with_bounds(-self.inner, self.bounds)
+    }
+}
 
 // equality
 impl<T: PartialEq> PartialEq<T> for Saturating<T> {
@@ -107,13 +260,25 @@ impl<T: PartialEq> PartialEq<Saturating<T>> for Saturating<T> {
 }
 impl<T: Eq> Eq for Saturating<T> {}
 
+// hashing has to agree with equality, so the bounds are ignored
+impl<T: Hash> Hash for Saturating<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.inner.hash(state) }
+}
+
+// Display only shows the inner value
+impl<T: fmt::Display> fmt::Display for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
 // We can only implement Debug if T implements Debug
 impl<T: fmt::Debug> fmt::Debug for Saturating<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "Saturating {{inner: {:?}, min: {:?}, max: {:?}}}",
-            self.inner, self.min, self.max
+            self.inner, self.bounds.min, self.bounds.max
         )
     }
 }
@@ -122,18 +287,63 @@ impl<T: fmt::Debug> fmt::Debug for Saturating<T> {
 impl<T: Clone> Clone for Saturating<T> {
     fn clone(&self) -> Self {
         Self {
-            inner: self.inner.clone(),
-            min:   self.min.clone(),
-            max:   self.max.clone(),
+            inner:  self.inner.clone(),
+            bounds: self.bounds.clone(),
         }
     }
 }
 impl<T: Copy> Copy for Saturating<T> {}
 
+#[cfg(feature = "serde")]
+crate::macros::impl_serde_generic!(
+    Saturating,
+    [PartialOrd + Clone],
+    |inner, min, max| Saturating::try_new(inner, min, max)
+        .map_err(serde::de::Error::custom)
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn for_slice() {
+        let items = ["a", "b", "c"];
+        let index = Saturating::for_slice(3, &items).unwrap();
+        assert_eq!(index, 2);
+        assert_eq!(items[index.into_inner()], "c");
+        assert_eq!(Saturating::for_slice(1, &items).unwrap(), 1);
+
+        let result = Saturating::for_slice(0, &[1]);
+        assert_eq!(result, Err(BoundsError::InvalidBounds));
+        let result = Saturating::for_slice(0, &[] as &[u8]);
+        assert_eq!(result, Err(BoundsError::InvalidBounds));
+    }
+
+    #[test]
+    fn works_without_rem() {
+        use std::time::Duration;
+
+        let (min, max) = (Duration::from_secs(1), Duration::from_secs(10));
+        let mut timeout = Saturating::new(Duration::from_secs(30), min, max);
+        assert_eq!(timeout, max);
+
+        timeout -= Duration::from_secs(4);
+        assert_eq!(timeout, Duration::from_secs(6));
+        timeout -= Duration::from_millis(5500);
+        assert_eq!(timeout, min);
+        timeout += Duration::from_millis(1500);
+        assert_eq!(timeout, Duration::from_millis(2500));
+        assert_eq!(timeout.clamp(Duration::ZERO), min);
+
+        // subtracting more than the current value would underflow Duration
+        let mut short = Saturating::new(Duration::from_secs(3), min, max);
+        short -= Duration::from_secs(5);
+        assert_eq!(short, min);
+        short -= Saturating::new(Duration::from_secs(8), min, max);
+        assert_eq!(short, min);
+    }
+
     #[test]
     fn test_create() {
         let foo = Saturating::new(3, -5, 74);
@@ -142,20 +352,73 @@ mod tests {
 
     #[test]
     fn test_debug() {
-        let foo = Saturating { inner: 3, min: -5, max: 74 };
+        let foo = Saturating::new(3, -5, 74);
         let out = format!("{:?}", foo);
         assert_eq!(&out, "Saturating {inner: 3, min: -5, max: 74}")
     }
 
     #[test]
     fn test_eq() {
-        let foo = Saturating { inner: 3, min: -5, max: 74 };
-        let bar = Saturating { inner: 3, min: 0, max: 74 };
+        let foo = Saturating::new(3, -5, 74);
+        let bar = Saturating::new(3, 0, 74);
         assert_eq!(foo, 3);
         assert_eq!(bar, 3);
         assert_eq!(foo, bar);
     }
 
+    #[test]
+    fn test_contains() {
+        let foo = Saturating::new(3, -2, 8);
+        assert!(foo.contains(&-2));
+        assert!(foo.contains(&8));
+        assert!(!foo.contains(&9));
+        assert!(!foo.contains(&-3));
+    }
+
+    #[test]
+    fn test_with_bounds() {
+        let bounds = Bounds::new(0, 10);
+        let foo = Saturating::with_bounds(23, bounds);
+        assert_eq!(foo, 10);
+        assert_eq!(foo.bounds(), &bounds);
+        assert_eq!(Saturating::with_bounds(-1, bounds), 0);
+    }
+
+    #[test]
+    fn test_from_range() {
+        let foo = Saturating::from_range(15, 0..=10).unwrap();
+        assert_eq!(foo, 10);
+        assert_eq!(foo.bounds(), &Bounds::new(0, 10));
+        assert_eq!(foo.range(), &0..=&10);
+
+        let bar = Saturating::from_range(-0.5, -1.0..=1.0).unwrap();
+        assert_eq!(bar, -0.5);
+
+        let empty = Saturating::from_range(5, 5..=5);
+        assert_eq!(empty, Err(BoundsError::InvalidBounds));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = Saturating::from_range(5, 10..=0);
+        assert_eq!(reversed, Err(BoundsError::InvalidBounds));
+    }
+
+    #[test]
+    fn test_min_max_with() {
+        let a = Saturating::new(3, 0, 10);
+        let b = Saturating::new(7, 5, 8);
+
+        let max = a.max_with(b);
+        assert_eq!(max, 7);
+        assert_eq!(max.bounds(), &Bounds::new(5, 8));
+        let min = a.min_with(b);
+        assert_eq!(min, 3);
+        assert_eq!(min.bounds(), &Bounds::new(0, 10));
+
+        // ties pick `other` for max, and `self` for min
+        let c = Saturating::new(7, 0, 10);
+        assert_eq!(b.max_with(c).bounds(), c.bounds());
+        assert_eq!(b.min_with(c).bounds(), b.bounds());
+    }
+
     #[test]
     fn test_max_and_min() {
         let foo = Saturating::new(4, -3, 8);
@@ -180,6 +443,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn saturating_methods_return_self() {
+        let a = Saturating::new(5, 2, 8);
+        let b: Saturating<i32> = a.saturating_add(5);
+        assert_eq!(b, 8);
+        assert_eq!(b.bounds(), a.bounds());
+
+        let c = a.saturating_add(5).saturating_mul(3).saturating_sub(4);
+        assert_eq!(c, 4);
+        assert_eq!(c.saturating_sub(20).saturating_div(2), 2);
+        assert_eq!(a.saturating_div(2).saturating_mul(-1), 2);
+    }
+
+    #[test]
+    fn test_scaled_and_offset() {
+        let foo = Saturating::new(0.75, -1.0, 1.0);
+        assert_eq!(foo.scaled(2.0), 1.0);
+        assert_eq!(foo.scaled(-0.5), -0.375);
+        assert_eq!(foo.offset(0.25), 1.0);
+        assert_eq!(foo.offset(-0.25), 0.5);
+        assert_eq!(foo.shifted_down(10.0), -1.0);
+
+        let bar = foo.scaled(-4.0).offset(0.5);
+        assert_eq!(bar, -0.5);
+        assert_eq!(bar.bounds(), foo.bounds());
+        assert_eq!(foo, 0.75);
+    }
+
     #[test]
     fn test_saturating() {
         let mut foo = Saturating::new(5.0, 0.0, 10.0);
@@ -190,4 +481,73 @@ mod tests {
         foo += 100.0;
         assert_eq!(foo, 10.0);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let foo = Saturating::new(3, -5, 74);
+        let json = serde_json::to_string(&foo).unwrap();
+        assert_eq!(json, r#"{"inner":3,"min":-5,"max":74}"#);
+        let bar: Saturating<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bar, foo);
+        assert_eq!(bar.range(), foo.range());
+
+        let foo = Saturating::new(2.5, 0.0, 10.0);
+        let json = serde_json::to_string(&foo).unwrap();
+        let bar: Saturating<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bar, foo);
+        assert_eq!(bar.range(), foo.range());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_deserialize_will_saturate() {
+        let json = r#"{"inner":12.0,"min":0.0,"max":10.0}"#;
+        let foo: Saturating<f64> = serde_json::from_str(json).unwrap();
+        assert_eq!(foo, 10.0);
+
+        let json = r#"{"inner":3.0,"min":10.0,"max":10.0}"#;
+        assert!(serde_json::from_str::<Saturating<f64>>(json).is_err());
+    }
+
+    #[test]
+    fn test_hash() {
+        use crate::test_utils::hash_of;
+
+        let foo = Saturating::new(3, -5, 74);
+        let bar = Saturating::new(3, 0, 74);
+        assert_eq!(foo, bar);
+        assert_eq!(hash_of(&foo), hash_of(&bar));
+    }
+
+    #[test]
+    fn test_display() {
+        let foo = Saturating::new(3.5, -5.0, 74.0);
+        assert_eq!(format!("{}", foo), "3.5");
+        assert_eq!(format!("{:.2}", foo), "3.50");
+    }
+
+    #[test]
+    fn test_neg() {
+        let foo = Saturating::new(40, -100, 50);
+        assert_eq!(-foo, -40);
+
+        let bar = Saturating::new(-80.0, -100.0, 50.0);
+        assert_eq!(-bar, 50.0);
+
+        let baz = Saturating::new(80.0, -50.0, 100.0);
+        assert_eq!(-baz, -50.0);
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Saturating::try_new(3, -5, 74).unwrap(), 3);
+        assert_eq!(Saturating::try_new(3, 5, 5), Err(BoundsError::InvalidBounds));
+        assert_eq!(Saturating::try_new(3, 10, 5), Err(BoundsError::InvalidBounds));
+        assert!(Saturating::try_new(0.0, 1.0, -1.0).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_use_invalid_bounds() { let _ = Saturating::new(3, 10, 5); }
 }
@@ -1,9 +1,17 @@
-use std::cmp::{Ord, Ordering, PartialOrd};
-use std::ops::{
-    Add, AddAssign, Div, DivAssign, Mul, MulAssign, RangeInclusive, Rem,
-    RemAssign, Sub, SubAssign,
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::num::ParseIntError;
+use core::ops::{
+    Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Neg, RangeInclusive,
+    Rem, RemAssign, Sub, SubAssign,
 };
+use core::str::FromStr;
 
+use crate::clamped::Clamped;
+use crate::guard::ClampGuard;
+use crate::iter::{All, Steps};
 use crate::macros::*;
 
 macro_rules! impl_create {
@@ -11,14 +19,11 @@ macro_rules! impl_create {
         impl<const MIN: $inner, const MAX: $inner> $type {
             pub fn new(inner: $inner) -> Self { Self::from(inner) }
             pub fn inner(&self) -> $inner { self.0 }
-            pub fn range(&self) -> RangeInclusive<$inner> { MIN..=MAX }
-            pub fn min_bound(&self) -> $inner { MIN }
-            pub fn max_bound(&self) -> $inner { MAX }
-        }
 
-        impl<const MIN: $inner, const MAX: $inner> From<$inner> for $type {
-            fn from(inner: $inner) -> Self {
-                assert!(MIN < MAX, "MIN must be less than MAX");
+            /// A `const` version of [`Self::new`], for use in `const` and
+            /// `static` items.
+            pub const fn new_const(inner: $inner) -> Self {
+                let () = Self::VALID_BOUNDS;
 
                 if inner >= MAX {
                     Self(MAX)
@@ -28,13 +33,97 @@ macro_rules! impl_create {
                     Self(inner)
                 }
             }
+
+            /// A `const` version of [`Self::inner`].
+            pub const fn inner_const(&self) -> $inner { self.0 }
+            pub fn range(&self) -> RangeInclusive<$inner> { MIN..=MAX }
+            pub fn min_bound(&self) -> $inner { MIN }
+            pub fn max_bound(&self) -> $inner { MAX }
+
+            pub fn is_at_min(&self) -> bool { self.0 == MIN }
+            pub fn is_at_max(&self) -> bool { self.0 == MAX }
+
+            /// Whether `inner` is already in range, and so would be left
+            /// unchanged by [`From::from`].
+            pub fn contains(inner: $inner) -> bool {
+                inner >= MIN && inner <= MAX
+            }
+
+            /// An alias for [`From::from`].
+            pub fn clamp(inner: $inner) -> Self { Self::from(inner) }
+
+            /// Converts from another integer type, saturating at the limits of
+            /// the inner type as well as at the bounds.
+            pub fn saturating_from<U>(value: U) -> Self
+            where
+                U: TryInto<$inner> + PartialOrd + Default,
+            {
+                // zero always fits, so a value that doesn't fit is either
+                // below the inner type's MIN or above its MAX
+                let below_zero = value < U::default();
+                match value.try_into() {
+                    Ok(inner) => Self::from(inner),
+                    Err(_) if below_zero => Self(MIN),
+                    Err(_) => Self(MAX),
+                }
+            }
+
+            /// Returns an iterator over every value in `MIN..=MAX`.
+            pub fn all() -> All<Self> {
+                let () = Self::VALID_BOUNDS;
+                let last = MAX.abs_diff(MIN) as u128;
+                All::new(last, |offset| {
+                    Self(MIN.wrapping_add(offset as $inner))
+                })
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> From<$inner> for $type {
+            fn from(inner: $inner) -> Self {
+                let value = Self::new_const(inner);
+                invariant_debug_assert!(value);
+                value
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> FromStr for $type {
+            type Err = ParseIntError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from(s.parse::<$inner>()?))
+            }
         }
     };
 }
 
 macro_rules! impl_all {
-    ($type:ty, $other:ty, $inner:ty) => {
+    ($type:ty, $other:ty, $inner:ty, $uinner:ty) => {
         impl_create!($type, $inner);
+        impl_valid_bounds!($type, $inner);
+        impl_width!($type, $inner, $uinner, MAX);
+
+        // The distance is returned unsigned, as the bounds of a signed type
+        // can be further apart than its own MAX.
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            pub fn dist_to_min(&self) -> $uinner { self.0.abs_diff(MIN) }
+            pub fn dist_to_max(&self) -> $uinner { MAX.abs_diff(self.0) }
+
+            /// Moves by a signed `delta`, saturating at either bound. This
+            /// allows unsigned types to be moved backwards as well.
+            pub fn offset(self, delta: i64) -> Self {
+                let distance = delta.unsigned_abs() as u128;
+                if delta < 0 {
+                    if distance >= self.dist_to_min() as u128 {
+                        return Self(MIN);
+                    }
+                    Self(self.0.wrapping_sub(distance as $inner))
+                } else {
+                    if distance >= self.dist_to_max() as u128 {
+                        return Self(MAX);
+                    }
+                    Self(self.0.wrapping_add(distance as $inner))
+                }
+            }
+        }
 
         impl_arith!($type, $other, $inner, Add, add, |this, other| this
             + other);
@@ -47,36 +136,230 @@ macro_rules! impl_all {
         impl_arith!($type, $other, $inner, Sub, sub, |this, other| this
             - other);
 
-        impl_arith_assign!($type, $other, $inner, AddAssign, add_assign, add);
-        impl_arith_assign!($type, $other, $inner, MulAssign, mul_assign, mul);
-        impl_arith_assign!($type, $other, $inner, DivAssign, div_assign, div);
         impl_arith_assign!($type, $other, $inner, RemAssign, rem_assign, rem);
 
         impl_ord!($type, $other, $inner);
+        impl_hash!($type, $inner);
+        impl_bound_consts!($type, $inner);
+        impl_as_float!($type, $inner);
+        impl_default!($type, $inner);
+        impl_display!($type, $inner);
+        impl_deref!($type, $inner);
+        impl_into_inner!($type, $inner);
+        impl_min_max!($type, $inner);
+        impl_apply!($type, $inner, $inner, |value| Self::from(value).0);
+        impl_map!($type, $inner, map, Self, Self::from);
+        impl_clamped!($type, $inner, Self, Self::from);
+        impl_inner_mut!($type, $inner);
+        impl_rebound!($type, $other, $inner, $other, <$other>::from);
+        impl_midpoint!($type, $inner, Self::from);
+        impl_sum!($type, $inner);
+        impl_step!($type, $inner);
+        impl_normalize!($type, $other, $inner);
 
-        impl<const MIN: $inner, const MAX: $inner> SubAssign<$inner> for $type {
-            fn sub_assign(&mut self, other: $inner) {
-                let result = {
-                    if other > self.0 - MIN {
-                        MIN
-                    } else {
-                        self.0 - other
-                    }
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Maps `t` from `0.0..=1.0` into the range, rounding to the
+            /// nearest value. `t` is clamped if it falls outside of
+            /// `0.0..=1.0`.
+            pub fn from_normalized(t: f64) -> Self {
+                Self(Self::denormalize(t))
+            }
+
+            /// Maps the value into another set of bounds by its relative
+            /// position, so a value 30% of the way from `MIN` to `MAX` stays
+            /// 30% of the way along. See [`Self::rebound`] to keep the
+            /// absolute value instead.
+            ///
+            /// The result is rounded to the nearest value, with ties rounding
+            /// up. Unlike [`Self::remap`], this is exact unless the
+            /// intermediate product overflows a `u128`, in which case it falls
+            /// back to `remap()`.
+            pub fn rescale<const OTHER_MIN: $inner, const OTHER_MAX: $inner>(
+                self,
+            ) -> $other {
+                let width = Self::width() as u128;
+                let scaled = (self.dist_to_min() as u128)
+                    .checked_mul(<$other>::width() as u128)
+                    .and_then(|scaled| scaled.checked_add(width / 2));
+
+                match scaled {
+                    Some(scaled) => {
+                        let offset = scaled / width;
+                        <$other>::from(OTHER_MIN.wrapping_add(offset as $inner))
+                    },
+                    None => self.remap(),
+                }
+            }
+
+            /// Rounds to the nearest `MIN + k * step`, rounding halves up. If
+            /// the nearest step is above `MAX`, the one below it is used.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `step` isn't positive.
+            pub fn quantize(self, step: $inner) -> Self {
+                assert!(step > 0, "step must be positive");
+
+                let step = step as $uinner;
+                let offset = self.dist_to_min();
+                let remainder = offset % step;
+                let lower = offset - remainder;
+
+                let offset = match lower.checked_add(step) {
+                    Some(upper)
+                        if remainder >= step - remainder
+                            && upper <= Self::width() =>
+                    {
+                        upper
+                    },
+                    _ => lower,
                 };
+                Self(MIN.wrapping_add(offset as $inner))
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl_num_bounded!($type, $inner, Self::from(MIN), Self::from(MAX));
+
+        #[cfg(feature = "serde")]
+        impl_serde!($type, $inner, |inner| Ok(Self::from(inner)));
 
-                *self = result.into();
+        // Unlike the arithmetic operators, these methods return a new
+        // saturated value rather than the raw result. The inner type's own
+        // saturating arithmetic is used, so they can never overflow.
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            pub fn saturating_add(self, rhs: $inner) -> Self {
+                strict_debug_assert!(
+                    self.0.checked_add(rhs),
+                    "saturated",
+                    self.0,
+                    "+",
+                    rhs,
+                    "..="
+                );
+                Self::from(self.0.saturating_add(rhs))
+            }
+            pub fn saturating_sub(self, rhs: $inner) -> Self {
+                strict_debug_assert!(
+                    self.0.checked_sub(rhs),
+                    "saturated",
+                    self.0,
+                    "-",
+                    rhs,
+                    "..="
+                );
+                Self::from(self.0.saturating_sub(rhs))
+            }
+            pub fn saturating_mul(self, rhs: $inner) -> Self {
+                strict_debug_assert!(
+                    self.0.checked_mul(rhs),
+                    "saturated",
+                    self.0,
+                    "*",
+                    rhs,
+                    "..="
+                );
+                Self::from(self.0.saturating_mul(rhs))
+            }
+            pub fn saturating_div(self, rhs: $inner) -> Self {
+                // dividing by zero panics here, before it can be mistaken
+                // for saturating below
+                let result = self.0.saturating_div(rhs);
+                strict_debug_assert!(
+                    self.0.checked_div(rhs),
+                    "saturated",
+                    self.0,
+                    "/",
+                    rhs,
+                    "..="
+                );
+                Self::from(result)
+            }
+            pub fn pow(self, exp: u32) -> Self {
+                Self::from(self.0.saturating_pow(exp))
+            }
+
+            /// Like [`Self::saturating_add`], but also returns whether the
+            /// result had to be clamped to one of the bounds.
+            pub fn saturating_add_clamped(self, rhs: $inner) -> (Self, bool) {
+                let clamped = match self.0.checked_add(rhs) {
+                    Some(result) => !Self::contains(result),
+                    None => true,
+                };
+                (Self::from(self.0.saturating_add(rhs)), clamped)
+            }
+            /// Like [`Self::saturating_sub`], but also returns whether the
+            /// result had to be clamped to one of the bounds.
+            pub fn saturating_sub_clamped(self, rhs: $inner) -> (Self, bool) {
+                let clamped = match self.0.checked_sub(rhs) {
+                    Some(result) => !Self::contains(result),
+                    None => true,
+                };
+                (Self::from(self.0.saturating_sub(rhs)), clamped)
+            }
+            /// Like [`Self::saturating_mul`], but also returns whether the
+            /// result had to be clamped to one of the bounds.
+            pub fn saturating_mul_clamped(self, rhs: $inner) -> (Self, bool) {
+                let clamped = match self.0.checked_mul(rhs) {
+                    Some(result) => !Self::contains(result),
+                    None => true,
+                };
+                (Self::from(self.0.saturating_mul(rhs)), clamped)
             }
         }
 
-        impl<
-                const MIN: $inner,
-                const MAX: $inner,
-                const OTHER_MIN: $inner,
-                const OTHER_MAX: $inner,
-            > SubAssign<$other> for $type
-        {
-            fn sub_assign(&mut self, other: $other) {
-                self.sub_assign(other.inner())
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            AddAssign,
+            add_assign,
+            saturating_add
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            SubAssign,
+            sub_assign,
+            saturating_sub
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            MulAssign,
+            mul_assign,
+            saturating_mul
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            DivAssign,
+            div_assign,
+            saturating_div
+        );
+    };
+}
+
+// Implementations that only make sense for signed integers.
+macro_rules! impl_signed {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> Neg for $type {
+            type Output = Self;
+            fn neg(self) -> Self::Output { Self::from(self.0.saturating_neg()) }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// The absolute value, saturated back into range. As with negation,
+            /// the most negative inner value doesn't cause an overflow.
+            pub fn abs(self) -> Self {
+                if self.0 < 0 {
+                    -self
+                } else {
+                    self
+                }
             }
         }
     };
@@ -84,51 +367,57 @@ macro_rules! impl_all {
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingU8<const MIN: u8, const MAX: u8>(u8);
-impl_all!(SaturatingU8<MIN, MAX>, SaturatingU8<OTHER_MIN, OTHER_MAX>, u8);
+impl_all!(SaturatingU8<MIN, MAX>, SaturatingU8<OTHER_MIN, OTHER_MAX>, u8, u8);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingU16<const MIN: u16, const MAX: u16>(u16);
-impl_all!(SaturatingU16<MIN, MAX>, SaturatingU16<OTHER_MIN, OTHER_MAX>, u16);
+impl_all!(SaturatingU16<MIN, MAX>, SaturatingU16<OTHER_MIN, OTHER_MAX>, u16, u16);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingU32<const MIN: u32, const MAX: u32>(u32);
-impl_all!(SaturatingU32<MIN, MAX>, SaturatingU32<OTHER_MIN, OTHER_MAX>, u32);
+impl_all!(SaturatingU32<MIN, MAX>, SaturatingU32<OTHER_MIN, OTHER_MAX>, u32, u32);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingU64<const MIN: u64, const MAX: u64>(u64);
-impl_all!(SaturatingU64<MIN, MAX>, SaturatingU64<OTHER_MIN, OTHER_MAX>, u64);
+impl_all!(SaturatingU64<MIN, MAX>, SaturatingU64<OTHER_MIN, OTHER_MAX>, u64, u64);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingU128<const MIN: u128, const MAX: u128>(u128);
-impl_all!(SaturatingU128<MIN, MAX>, SaturatingU128<OTHER_MIN, OTHER_MAX>, u128);
+impl_all!(SaturatingU128<MIN, MAX>, SaturatingU128<OTHER_MIN, OTHER_MAX>, u128, u128);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingUSize<const MIN: usize, const MAX: usize>(usize);
-impl_all!(SaturatingUSize<MIN, MAX>, SaturatingUSize<OTHER_MIN, OTHER_MAX>, usize);
+impl_all!(SaturatingUSize<MIN, MAX>, SaturatingUSize<OTHER_MIN, OTHER_MAX>, usize, usize);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingI8<const MIN: i8, const MAX: i8>(i8);
-impl_all!(SaturatingI8<MIN, MAX>, SaturatingI8<OTHER_MIN, OTHER_MAX>, i8);
+impl_all!(SaturatingI8<MIN, MAX>, SaturatingI8<OTHER_MIN, OTHER_MAX>, i8, u8);
+impl_signed!(SaturatingI8<MIN, MAX>, i8);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingI16<const MIN: i16, const MAX: i16>(i16);
-impl_all!(SaturatingI16<MIN, MAX>, SaturatingI16<OTHER_MIN, OTHER_MAX>, i16);
+impl_all!(SaturatingI16<MIN, MAX>, SaturatingI16<OTHER_MIN, OTHER_MAX>, i16, u16);
+impl_signed!(SaturatingI16<MIN, MAX>, i16);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingI32<const MIN: i32, const MAX: i32>(i32);
-impl_all!(SaturatingI32<MIN, MAX>, SaturatingI32<OTHER_MIN, OTHER_MAX>, i32);
+impl_all!(SaturatingI32<MIN, MAX>, SaturatingI32<OTHER_MIN, OTHER_MAX>, i32, u32);
+impl_signed!(SaturatingI32<MIN, MAX>, i32);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingI64<const MIN: i64, const MAX: i64>(i64);
-impl_all!(SaturatingI64<MIN, MAX>, SaturatingI64<OTHER_MIN, OTHER_MAX>, i64);
+impl_all!(SaturatingI64<MIN, MAX>, SaturatingI64<OTHER_MIN, OTHER_MAX>, i64, u64);
+impl_signed!(SaturatingI64<MIN, MAX>, i64);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingI128<const MIN: i128, const MAX: i128>(i128);
-impl_all!(SaturatingI128<MIN, MAX>, SaturatingI128<OTHER_MIN, OTHER_MAX>, i128);
+impl_all!(SaturatingI128<MIN, MAX>, SaturatingI128<OTHER_MIN, OTHER_MAX>, i128, u128);
+impl_signed!(SaturatingI128<MIN, MAX>, i128);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SaturatingISize<const MIN: isize, const MAX: isize>(isize);
-impl_all!(SaturatingISize<MIN, MAX>, SaturatingISize<OTHER_MIN, OTHER_MAX>, isize);
+impl_all!(SaturatingISize<MIN, MAX>, SaturatingISize<OTHER_MIN, OTHER_MAX>, isize, usize);
+impl_signed!(SaturatingISize<MIN, MAX>, isize);
 
 #[cfg(test)]
 mod tests {
@@ -163,6 +452,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn overflow_will_saturate() {
         let mut a = SaturatingU32::<0, 10>(4);
         assert_eq!(a + 8, 12);
@@ -176,6 +466,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn bounded_underflow_will_saturate() {
         let mut a = SaturatingU32::<4, 8>(6);
         assert_eq!(a - 3, 3);
@@ -189,6 +480,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn real_underflow_will_saturate() {
         let mut a = SaturatingU32::<1, 4>(2);
         a -= 4000001;
@@ -196,6 +488,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn addassign_matches_new() {
         let mut a = SaturatingU32::<0, 10>(4);
 
@@ -263,6 +556,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn range_is_inclusive() {
         let mut a = SaturatingUSize::<0, 10>::from(5);
         a += 10;
@@ -270,6 +564,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn range_is_inclusive_signed() {
         let mut a = SaturatingISize::<-10, 10>::from(5);
         a -= 20;
@@ -277,12 +572,578 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn cannot_use_equal_bounds() { let _ = SaturatingUSize::<10, 10>::from(5); }
+    #[cfg(feature = "serde")]
+    fn serde_only_writes_inner() {
+        let a = SaturatingU32::<2, 8>::from(5);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, "5");
+
+        let b: SaturatingU32<2, 8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize_will_saturate() {
+        let a: SaturatingU32<2, 8> = serde_json::from_str("10").unwrap();
+        assert_eq!(a, 8);
+
+        let b: SaturatingI32<-10, 10> = serde_json::from_str("-12").unwrap();
+        assert_eq!(b, -10);
+    }
+
+    #[test]
+    fn hash_matches_eq() {
+        use crate::test_utils::hash_of;
+
+        let a = SaturatingU8::<0, 16>::new(5);
+        let b = SaturatingU8::<2, 10>::new(5);
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn default_is_min() {
+        assert_eq!(SaturatingU32::<2, 8>::default(), 2);
+        assert_eq!(SaturatingU8::<0, 10>::default(), 0);
+        assert_eq!(SaturatingI32::<-10, 10>::default(), -10);
+        assert_eq!(SaturatingI64::<3, 5>::default(), 3);
+    }
+
+    #[test]
+    fn display_shows_inner() {
+        let a = SaturatingI32::<-10, 10>::new(-5);
+        assert_eq!(format!("{}", a), "-5");
+        assert_eq!(format!("{:>4}", a), "  -5");
+        assert_eq!(format!("{:?}", a), "SaturatingI32(-5)");
+    }
+
+    #[test]
+    fn parse_will_saturate() {
+        assert_eq!("5".parse::<SaturatingU32<2, 8>>().unwrap(), 5);
+        assert_eq!("10".parse::<SaturatingU32<2, 8>>().unwrap(), 8);
+        assert_eq!("-12".parse::<SaturatingI32<-10, 10>>().unwrap(), -10);
+        assert!("abc".parse::<SaturatingU32<2, 8>>().is_err());
+        assert!("".parse::<SaturatingU32<2, 8>>().is_err());
+    }
+
+    #[test]
+    fn neg_will_saturate() {
+        let a = SaturatingI32::<-100, 50>::new(40);
+        assert_eq!(-a, -40);
+
+        let b = SaturatingI32::<-100, 50>::new(-80);
+        assert_eq!(-b, 50);
+
+        let c = SaturatingI8::<-128, 100>::new(-128);
+        assert_eq!(-c, 100);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn saturating_methods_return_self() {
+        let a = SaturatingU32::<2, 8>::from(5);
+        let b: SaturatingU32<2, 8> = a.saturating_add(5);
+        assert_eq!(b, 8);
+
+        // chained operations stay within bounds
+        let c = a.saturating_add(5).saturating_mul(3).saturating_sub(4);
+        assert_eq!(c, 4);
+        assert_eq!(a.saturating_sub(100), 2);
+        assert_eq!(a.saturating_div(5), 2);
+    }
+
+    #[test]
+    fn clamped_methods() {
+        type A = SaturatingU32<2, 8>;
+        let a = A::from(5);
+        assert_eq!(a.saturating_add_clamped(3), (A::from(8), false));
+        assert_eq!(a.saturating_add_clamped(4), (A::from(8), true));
+        assert_eq!(a.saturating_sub_clamped(3), (A::from(2), false));
+        assert_eq!(a.saturating_sub_clamped(4), (A::from(2), true));
+        assert_eq!(a.saturating_sub_clamped(9), (A::from(2), true));
+        assert_eq!(a.saturating_mul_clamped(1), (A::from(5), false));
+        assert_eq!(a.saturating_mul_clamped(2), (A::from(8), true));
+
+        // overflowing the inner type always counts as clamping
+        type B = SaturatingI8<-100, 100>;
+        let b = B::from(90);
+        assert_eq!(b.saturating_add_clamped(-10), (B::from(80), false));
+        assert_eq!(b.saturating_add_clamped(100), (B::from(100), true));
+        assert_eq!(b.saturating_mul_clamped(-2), (B::from(-100), true));
+        assert_eq!(b.saturating_sub_clamped(-90), (B::from(100), true));
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn is_at_bound() {
+        let mut a = SaturatingU32::<2, 8>::clamp(5);
+        assert!(!a.is_at_min());
+        assert!(!a.is_at_max());
+
+        a += 100;
+        assert_eq!(a, 8);
+        assert!(a.is_at_max());
+
+        a -= 100;
+        assert!(a.is_at_min());
+        assert_eq!(SaturatingU32::<2, 8>::clamp(100), 8);
+    }
+
+    #[test]
+    fn contains() {
+        assert!(SaturatingI32::<-2, 8>::contains(-2));
+        assert!(SaturatingI32::<-2, 8>::contains(8));
+        assert!(!SaturatingI32::<-2, 8>::contains(9));
+        assert!(!SaturatingI32::<-2, 8>::contains(-3));
+        assert!(SaturatingU8::<0, 255>::contains(255));
+    }
+
+    #[test]
+    fn dist_to_bounds() {
+        let a = SaturatingU32::<2, 8>::from(5);
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (3, 3));
+        let a = SaturatingU32::<2, 8>::from(2);
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (0, 6));
+        let a = SaturatingU32::<2, 8>::from(8);
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (6, 0));
+
+        let a = SaturatingI8::<-128, 127>::from(127);
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (255, 0));
+        let a = SaturatingI8::<-128, 127>::from(-128);
+        assert_eq!((a.dist_to_min(), a.dist_to_max()), (0, 255));
+    }
+
+    #[test]
+    fn offset() {
+        let a = SaturatingU8::<0, 100>::from(10);
+        assert_eq!(a.offset(-3), 7);
+        assert_eq!(a.offset(-10), 0);
+        assert_eq!(a.offset(-1000), 0);
+        assert_eq!(a.offset(i64::MIN), 0);
+        assert_eq!(a.offset(90), 100);
+        assert_eq!(a.offset(1000), 100);
+        assert_eq!(a.offset(i64::MAX), 100);
+
+        let b = SaturatingI128::<{ i128::MIN }, { i128::MAX }>::from(-1);
+        assert_eq!(b.offset(i64::MIN), i64::MIN as i128 - 1);
+        assert_eq!(b.offset(i64::MAX), i64::MAX as i128 - 1);
+        let c = SaturatingU128::<5, { u128::MAX }>::from(u128::MAX - 1);
+        assert_eq!(c.offset(2), u128::MAX);
+        assert_eq!(c.offset(-2), u128::MAX - 3);
+    }
+
+    #[test]
+    fn inner_mut() {
+        let mut a = SaturatingU8::<0, 100>::from(10);
+        {
+            let mut inner = a.inner_mut();
+            *inner *= 20;
+            assert_eq!(*inner, 200);
+            *inner -= 50;
+        }
+        assert_eq!(a, 100);
+
+        *a.inner_mut() = 0;
+        assert!(a.is_at_min());
+    }
+
+    #[test]
+    fn set() {
+        let mut a = SaturatingU8::<0, 100>::from(10);
+        a.set(30);
+        assert_eq!(a, 30);
+        a.set(200);
+        assert_eq!(a, 100);
+    }
+
+    #[test]
+    fn rebound() {
+        let a = SaturatingU32::<0, 1000>::from(500);
+        assert_eq!(a.rebound::<0, 100>(), 100);
+        assert_eq!(a.rebound::<600, 700>(), 600);
+        assert_eq!(a.rebound::<0, 500>(), 500);
+        assert_eq!(a.rebound::<400, 2000>(), 500);
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(SaturatingI32::<-100, 50>::from(-80).abs(), 50);
+        assert_eq!(SaturatingI32::<-100, 50>::from(-30).abs(), 30);
+        assert_eq!(SaturatingI32::<-100, 50>::from(30).abs(), 30);
+        assert_eq!(SaturatingI8::<-128, 127>::from(-128).abs(), 127);
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(SaturatingU32::<0, 100>::from(3).pow(4), 81);
+        assert_eq!(SaturatingU32::<0, 100>::from(10).pow(10), 100);
+        assert_eq!(SaturatingU32::<0, 100>::from(10).pow(u32::MAX), 100);
+        assert_eq!(SaturatingU32::<5, 100>::from(10).pow(0), 5);
+        assert_eq!(SaturatingI32::<-50, 50>::from(-10).pow(3), -50);
+        assert_eq!(SaturatingI32::<-50, 50>::from(-10).pow(4), 50);
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(SaturatingU8::<0, 255>::midpoint(), 127);
+        assert_eq!(SaturatingI32::<-10, 11>::midpoint(), 0);
+        let wide = SaturatingI128::<{ i128::MIN }, { i128::MAX }>::midpoint();
+        assert_eq!(wide, -1);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(SaturatingU32::<2, 10>::from(2).normalize(), 0.0);
+        assert_eq!(SaturatingU32::<2, 10>::from(6).normalize(), 0.5);
+        assert_eq!(SaturatingU32::<2, 10>::from(10).normalize(), 1.0);
+        assert_eq!(SaturatingI8::<-128, 127>::from(127).normalize(), 1.0);
+    }
+
+    #[test]
+    fn test_from_normalized() {
+        assert_eq!(SaturatingU32::<2, 10>::from_normalized(0.0), 2);
+        assert_eq!(SaturatingU32::<2, 10>::from_normalized(0.5), 6);
+        assert_eq!(SaturatingU32::<2, 10>::from_normalized(1.0), 10);
+        assert_eq!(SaturatingU32::<2, 10>::from_normalized(0.3), 4);
+        assert_eq!(SaturatingU32::<2, 10>::from_normalized(-3.0), 2);
+        assert_eq!(SaturatingU32::<2, 10>::from_normalized(3.0), 10);
+        assert_eq!(SaturatingI8::<-128, 127>::from_normalized(1.0), 127);
+        assert_eq!(SaturatingI8::<-128, 127>::from_normalized(0.0), -128);
+    }
+
+    #[test]
+    fn normalize_round_trips() {
+        for i in -100..=100 {
+            let a = SaturatingI32::<-100, 100>::from(i);
+            let b = SaturatingI32::<-100, 100>::from_normalized(a.normalize());
+            assert_eq!(a, b);
+        }
+        for i in 0..=255 {
+            let a = SaturatingU8::<0, 255>::from(i);
+            let b = SaturatingU8::<0, 255>::from_normalized(a.normalize());
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_remap() {
+        type Adc = SaturatingU32<0, 1023>;
+        type Percent = SaturatingU32<0, 100>;
+
+        assert_eq!(Adc::from(0).remap::<0, 100>(), 0);
+        assert_eq!(Adc::from(1023).remap::<0, 100>(), 100);
+        // 512 / 1023 * 100 = 50.05
+        assert_eq!(Adc::from(512).remap::<0, 100>(), 50);
+
+        let percent: Percent = Adc::from(1023).remap();
+        assert!(percent.is_at_max());
+        assert_eq!(Percent::from(50).remap::<0, 1023>(), 512);
+        assert_eq!(SaturatingI32::<-10, 10>::from(0).remap::<100, 200>(), 150);
+    }
+
+    #[test]
+    #[cfg(all(feature = "strict-debug", debug_assertions))]
+    #[should_panic(expected = "-3 - 2 was saturated to fit in -4..=4")]
+    fn strict_debug_panics_on_saturate() {
+        let _ = SaturatingI8::<-4, 4>::from(-3).saturating_sub(2);
+    }
+
+    // Even with strict-debug, this is the usual panic rather than a saturation.
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_by_zero_panics() {
+        let _ = SaturatingI8::<-4, 4>::from(3).saturating_div(0);
+    }
+
+    #[test]
+    #[cfg(feature = "strict-debug")]
+    fn strict_debug_allows_in_range_ops() {
+        let mut a = SaturatingU8::<0, 10>::from(4);
+        assert_eq!(a.saturating_add(6), 10);
+        assert_eq!(a.saturating_sub(4), 0);
+        assert_eq!(a.saturating_mul(2), 8);
+        assert_eq!(a.saturating_div(3), 1);
+        a *= 2;
+        assert_eq!(a, 8);
+
+        assert_eq!(a.saturating_add_clamped(5), (SaturatingU8::from(10), true));
+        assert_eq!(SaturatingU8::<0, 10>::from(50), 10);
+    }
+
+    #[test]
+    fn saturating_from() {
+        type Full = SaturatingU8<0, { u8::MAX }>;
+        assert_eq!(Full::saturating_from(1000u32), 255);
+        assert_eq!(Full::saturating_from(-5i64), 0);
+        assert_eq!(Full::saturating_from(i128::MIN), 0);
+        assert_eq!(Full::saturating_from(200usize), 200);
+
+        type Small = SaturatingI8<-10, 10>;
+        assert_eq!(Small::saturating_from(u128::MAX), 10);
+        assert_eq!(Small::saturating_from(-1000i16), -10);
+        assert_eq!(Small::saturating_from(-100i8), -10);
+        assert_eq!(Small::saturating_from(7u64), 7);
+
+        assert_eq!(SaturatingU32::<5, 10>::saturating_from(-1i32), 5);
+        assert_eq!(SaturatingU32::<5, 10>::saturating_from(u64::MAX), 10);
+    }
+
+    #[test]
+    fn test_quantize() {
+        type Volume = SaturatingU32<0, 100>;
+
+        assert_eq!(Volume::from(42).quantize(10), 40);
+        assert_eq!(Volume::from(45).quantize(10), 50);
+        assert_eq!(Volume::from(100).quantize(10), 100);
+        assert_eq!(Volume::from(7).quantize(1), 7);
+
+        // 30 doesn't divide 100, so the steps are 0, 30, 60 and 90
+        assert_eq!(Volume::from(14).quantize(30), 0);
+        assert_eq!(Volume::from(15).quantize(30), 30);
+        assert_eq!(Volume::from(80).quantize(30), 90);
+        assert_eq!(Volume::from(100).quantize(30), 90);
+        assert_eq!(Volume::from(5).quantize(200), 0);
+
+        // steps are counted from MIN rather than from zero
+        let a = SaturatingI8::<-7, 7>::from(0);
+        assert_eq!(a.quantize(4), 1);
+        assert_eq!(SaturatingI8::<-7, 7>::from(7).quantize(4), 5);
+        assert_eq!(SaturatingI8::<-128, 127>::from(127).quantize(100), 72);
+
+        let b = SaturatingU8::<0, 255>::from(254);
+        assert_eq!(b.quantize(127), 254);
+    }
 
     #[test]
     #[should_panic]
-    fn cannot_use_invalid_bounds() {
-        let _ = SaturatingUSize::<15, 10>::from(5);
+    fn quantize_panics_on_zero_step() {
+        let _ = SaturatingU32::<0, 100>::from(5).quantize(0);
+    }
+
+    #[test]
+    fn test_rescale() {
+        type Percent = SaturatingU32<0, 100>;
+
+        assert_eq!(Percent::from(30).rescale::<0, 1000>(), 300);
+        assert_eq!(Percent::from(0).rescale::<0, 1000>(), 0);
+        assert_eq!(Percent::from(100).rescale::<0, 1000>(), 1000);
+        assert_eq!(Percent::from(50).rescale::<0, 10>(), 5);
+        assert_eq!(Percent::from(50).rescale::<20, 30>(), 25);
+
+        // 1/3 * 10 = 3.33, 2/3 * 10 = 6.67, and 1/2 * 5 = 2.5
+        assert_eq!(SaturatingU8::<0, 3>::from(1).rescale::<0, 10>(), 3);
+        assert_eq!(SaturatingU8::<0, 3>::from(2).rescale::<0, 10>(), 7);
+        assert_eq!(SaturatingU8::<0, 2>::from(1).rescale::<0, 5>(), 3);
+
+        // signed and full-range bounds
+        let a = SaturatingI8::<-128, 127>::from(127);
+        assert_eq!(a.rescale::<-1, 1>(), 1);
+        assert_eq!(SaturatingI8::<-10, 10>::from(0).rescale::<-128, 127>(), 0);
+
+        // exact where the f64-based remap() loses precision
+        type Wide = SaturatingU64<0, { u64::MAX }>;
+        let b: Wide = SaturatingU64::<0, 2>::from(1).rescale();
+        assert_eq!(b, u64::MAX / 2 + 1);
+        let c: Wide = Wide::from(u64::MAX - 1).rescale();
+        assert_eq!(c, u64::MAX - 1);
+
+        // falls back to remap() if the product is too large
+        type Huge = SaturatingU128<0, { u128::MAX }>;
+        assert_eq!(Huge::from(u128::MAX).rescale::<0, 10>(), 10);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn mul_near_inner_max_saturates() {
+        let a = SaturatingU8::<0, 200>::from(100);
+        assert_eq!(a.saturating_mul(3), 200);
+
+        let mut b = SaturatingU8::<0, 200>::from(100);
+        b *= 3;
+        assert_eq!(b, 200);
+
+        let mut c = SaturatingI8::<-100, 100>::from(-50);
+        c *= 100;
+        assert_eq!(c, -100);
+        c *= -100;
+        assert_eq!(c, 100);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn add_near_inner_max_saturates() {
+        let mut a = SaturatingU8::<0, 255>::from(250);
+        a += 100;
+        assert_eq!(a, 255);
+
+        let mut b = SaturatingI8::<-128, 127>::from(-100);
+        b += -100;
+        assert_eq!(b, -128);
+        b -= -100;
+        b -= -100;
+        assert_eq!(b, 72);
+        b -= -100;
+        assert_eq!(b, 127);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn sub_with_extreme_signed_bounds() {
+        // self - MIN doesn't fit in the inner type for any of these
+        let mut a = SaturatingI8::<{ i8::MIN }, { i8::MAX }>::from(127);
+        a -= i8::MAX;
+        assert_eq!(a, 0);
+        a -= i8::MAX;
+        a -= i8::MAX;
+        assert_eq!(a, -128);
+        assert_eq!(a.saturating_sub(i8::MAX), -128);
+        assert_eq!(a.saturating_sub(i8::MIN), 0);
+
+        type Wide = SaturatingI128<{ i128::MIN }, { i128::MAX }>;
+        let b = Wide::from(i128::MAX);
+        let b = b.saturating_sub(i128::MAX).saturating_sub(i128::MAX);
+        assert_eq!(b, -i128::MAX);
+        assert_eq!(Wide::from(-1).saturating_sub(i128::MAX), i128::MIN);
+        assert_eq!(Wide::from(i128::MIN).saturating_sub(1), i128::MIN);
+
+        let mut c = SaturatingI32::<-1000, 1000>::from(999);
+        c -= i32::MAX;
+        assert_eq!(c, -1000);
+        c -= i32::MIN;
+        assert_eq!(c, 1000);
+        c -= SaturatingI32::<{ i32::MIN }, 0>::from(i32::MIN);
+        assert_eq!(c, 1000);
+        c -= SaturatingI32::<0, { i32::MAX }>::from(i32::MAX);
+        assert_eq!(c, -1000);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn full_range_bounds() {
+        type Full = SaturatingI8<{ i8::MIN }, { i8::MAX }>;
+        assert_eq!(Full::from(100).saturating_add(100), 127);
+        assert_eq!(Full::from(-100).saturating_sub(100), -128);
+        assert_eq!(Full::from(-128).saturating_sub(-1), -127);
+        assert_eq!(Full::from(127).saturating_add(-128), -1);
+
+        let mut a = Full::from(-128);
+        a -= 127;
+        assert_eq!(a, -128);
+        a += 127;
+        a += 127;
+        a += 127;
+        assert_eq!(a, 127);
+        a -= -128;
+        assert_eq!(a, 127);
+
+        assert_eq!(a.dist_to_min(), 255);
+        assert_eq!(Full::from(-128).dist_to_max(), 255);
+        assert_eq!(Full::from(0).offset(1000), 127);
+        assert_eq!(Full::from(0).offset(-1000), -128);
+        assert_eq!(Full::all().count(), 256);
+        assert_eq!(Full::from(-128).normalize(), 0.0);
+        assert_eq!(Full::from(127).normalize(), 1.0);
+    }
+
+    #[test]
+    fn width_and_cardinality() {
+        type Full = SaturatingU8<{ u8::MIN }, { u8::MAX }>;
+        assert_eq!(Full::width(), 255);
+        assert_eq!(Full::cardinality(), 256);
+        assert_eq!(Full::cardinality(), Full::all().count() as u128);
+
+        assert_eq!(SaturatingI8::<-128, 127>::width(), 255u8);
+        assert_eq!(SaturatingI8::<-128, 127>::cardinality(), 256);
+        assert_eq!(SaturatingU32::<2, 8>::cardinality(), 7);
+    }
+
+    #[test]
+    fn map() {
+        let a = SaturatingU32::<2, 8>::from(6);
+        assert_eq!(a.map(|x| x * 2), 8);
+        assert_eq!(a.map(|x| x / 6), 2);
+        assert_eq!(a.map(|x| x - 1), 5);
+    }
+
+    #[test]
+    fn apply() {
+        assert_eq!(SaturatingU32::<2, 8>::apply(10), 8);
+        assert_eq!(SaturatingU32::<2, 8>::apply(0), 2);
+        assert_eq!(SaturatingI8::<-10, 10>::apply(-5), -5);
+    }
+
+    #[test]
+    fn min_max_with() {
+        let a = SaturatingU32::<2, 8>::from(3);
+        let b = SaturatingU32::<2, 8>::from(100);
+        let max: SaturatingU32<2, 8> = a.max_with(b);
+        assert_eq!(max, 8);
+        assert_eq!(a.min_with(b), 3);
+        assert_eq!(a.max_with(a), a);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn div_near_inner_min_saturates() {
+        let mut a = SaturatingI8::<-128, 127>::from(-128);
+        a /= -1;
+        assert_eq!(a, 127);
+    }
+
+    #[test]
+    fn test_all() {
+        let all: Vec<_> = SaturatingU32::<3, 7>::all().collect();
+        assert_eq!(all, [3, 4, 5, 6, 7]);
+        assert_eq!(SaturatingU32::<3, 7>::all().len(), 5);
+        assert_eq!(SaturatingI8::<-128, 127>::all().len(), 256);
+        assert_eq!(SaturatingI8::<-128, 127>::all().next_back().unwrap(), 127);
+    }
+
+    #[test]
+    fn test_step_by() {
+        type Foo = SaturatingU8<0, 255>;
+        let steps: Vec<_> =
+            Foo::step_by(Foo::from(100), Foo::from(255), 50).collect();
+        assert_eq!(steps, [100, 150, 200, 250]);
+
+        let steps: Vec<_> =
+            Foo::step_by(Foo::from(250), Foo::from(255), 200).collect();
+        assert_eq!(steps, [250]);
+
+        assert_eq!(Foo::step_by(Foo::from(5), Foo::from(2), 1).count(), 0);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn test_sum() {
+        let values = [400, 300, 500, 100];
+        let sum: SaturatingU32<0, 1000> = values.iter().copied().sum();
+        assert_eq!(sum, 1000);
+        assert!(sum.is_at_max());
+
+        let values = [400, 300, 200];
+        let sum: SaturatingU32<0, 1000> = values.iter().copied().sum();
+        assert_eq!(sum, 900);
+
+        // zero is out of range, so the sum starts at MIN
+        let sum: SaturatingU32<5, 1000> = [1, 2].into_iter().sum();
+        assert_eq!(sum, 8);
+        let sum: SaturatingU32<5, 1000> = core::iter::empty::<u32>().sum();
+        assert_eq!(sum, 5);
+
+        let values = [SaturatingU8::<0, 200>::from(150); 3];
+        let sum: SaturatingU8<0, 200> = values.into_iter().sum();
+        assert_eq!(sum, 200);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn test_product() {
+        let product: SaturatingU8<0, 200> = [10, 10, 10].into_iter().product();
+        assert_eq!(product, 200);
+        let product: SaturatingI32<-100, 100> =
+            [-5, 4, 2].into_iter().product();
+        assert_eq!(product, -40);
     }
 }
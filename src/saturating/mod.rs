@@ -1,7 +1,11 @@
 //! Types that saturate to fit within their given bounds.
 
+mod exclusive;
+mod float;
 mod generic;
 mod int;
 
+pub use exclusive::*;
+pub use float::*;
 pub use generic::*;
 pub use int::*;
@@ -0,0 +1,62 @@
+//! Clamping whole slices in place, without collecting them into a new
+//! collection of concrete types.
+//!
+//! ```rust
+//! use clamps::slice::clamp_slice_saturating;
+//!
+//! let mut samples = [0, 40, 900];
+//! clamp_slice_saturating::<10, 100>(&mut samples);
+//! assert_eq!(samples, [10, 40, 100]);
+//! ```
+
+use core::ops::Deref;
+
+use crate::saturating::SaturatingU32;
+use crate::wrapping::WrappingU32;
+
+// Replaces each element with its clamped equivalent, as given by C.
+fn clamp_in_place<T: Copy, C: From<T> + Deref<Target = T>>(slice: &mut [T]) {
+    for value in slice {
+        *value = *C::from(*value);
+    }
+}
+
+/// Saturates each element into `MIN..=MAX`, as [`SaturatingU32`] would.
+pub fn clamp_slice_saturating<const MIN: u32, const MAX: u32>(
+    slice: &mut [u32],
+) {
+    clamp_in_place::<u32, SaturatingU32<MIN, MAX>>(slice)
+}
+
+/// Wraps each element into `MIN..MAX`, as [`WrappingU32`] would.
+pub fn clamp_slice_wrapping<const MIN: u32, const MAX: u32>(
+    slice: &mut [u32],
+) {
+    clamp_in_place::<u32, WrappingU32<MIN, MAX>>(slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saturating() {
+        let mut buffer = [0, 4, 5, 7, 10, 11, u32::MAX];
+        clamp_slice_saturating::<5, 10>(&mut buffer);
+        assert_eq!(buffer, [5, 5, 5, 7, 10, 10, 10]);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let mut buffer = [0, 4, 5, 7, 10, 11, u32::MAX];
+        clamp_slice_wrapping::<5, 10>(&mut buffer);
+        assert_eq!(buffer, [5, 9, 5, 7, 5, 6, 5]);
+    }
+
+    #[test]
+    fn empty_slice_is_unchanged() {
+        let mut buffer: [u32; 0] = [];
+        clamp_slice_wrapping::<5, 10>(&mut buffer);
+        clamp_slice_saturating::<5, 10>(&mut buffer);
+    }
+}
@@ -0,0 +1,12 @@
+//! Helpers shared between the unit tests of each module.
+
+use core::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+
+/// Hashes `value` with the standard library's default hasher, for checking
+/// that values which compare equal also hash the same.
+pub(crate) fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
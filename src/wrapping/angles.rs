@@ -0,0 +1,64 @@
+//! Angles, which wrap around every full turn.
+
+use core::f64::consts::PI;
+
+use super::WrappingU32;
+
+/// A whole number of degrees in `0..360`.
+///
+/// ```rust
+/// use clamps::wrapping::angles::Degrees;
+///
+/// let heading = Degrees::from(350) + 20;
+/// assert_eq!(Degrees::from(heading), 10);
+/// ```
+pub type Degrees = WrappingU32<0, 360>;
+
+impl Degrees {
+    pub fn to_radians_f64(self) -> f64 { self.inner() as f64 * PI / 180.0 }
+
+    /// Converts from radians, rounding to the nearest degree. Any angle is
+    /// accepted, with negative angles wrapping around into `0..360`. NaN and
+    /// infinite values become `0`.
+    pub fn from_radians_f64(radians: f64) -> Self {
+        let mut degrees = (radians * 180.0 / PI) % 360.0;
+        if degrees < 0.0 {
+            degrees += 360.0;
+        }
+
+        // f64::round() isn't available without std, but degrees is never
+        // negative here. Rounding up to 360 is wrapped back to 0.
+        Self::from((degrees + 0.5) as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_radians() {
+        assert_eq!(Degrees::from(0).to_radians_f64(), 0.0);
+        assert_eq!(Degrees::from(180).to_radians_f64(), PI);
+        assert_eq!(Degrees::from(450).to_radians_f64(), PI / 2.0);
+    }
+
+    #[test]
+    fn from_radians() {
+        assert_eq!(Degrees::from_radians_f64(PI / 2.0), 90);
+        assert_eq!(Degrees::from_radians_f64(-PI / 2.0), 270);
+        assert_eq!(Degrees::from_radians_f64(-5.0 * PI / 2.0), 270);
+        assert_eq!(Degrees::from_radians_f64(4.0 * PI), 0);
+        assert_eq!(Degrees::from_radians_f64(-0.001), 0);
+        assert_eq!(Degrees::from_radians_f64(f64::NAN), 0);
+    }
+
+    #[test]
+    fn round_trip() {
+        for degrees in Degrees::all() {
+            let radians = degrees.to_radians_f64();
+            assert_eq!(Degrees::from_radians_f64(radians), degrees);
+            assert_eq!(Degrees::from_radians_f64(radians - 2.0 * PI), degrees);
+        }
+    }
+}
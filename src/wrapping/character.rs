@@ -0,0 +1,116 @@
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use crate::macros::*;
+
+/// A `char` that wraps around within `MIN..MAX` by code point. As with the
+/// other wrapping types, `MAX` is exclusive, so the lowercase alphabet is
+/// `'a'..'{'`:
+///
+/// ```rust
+/// use clamps::wrapping::WrappingChar;
+///
+/// type Lowercase = WrappingChar<'a', '{'>;
+///
+/// let rot13 = |c| Lowercase::new(c).shift(13).inner();
+/// assert_eq!("hello".chars().map(rot13).collect::<String>(), "uryyb");
+/// ```
+///
+/// As the surrogate code points aren't valid `char`s, the bounds can't span
+/// them.
+#[derive(Debug, Clone, Copy)]
+pub struct WrappingChar<const MIN: char, const MAX: char>(char);
+
+impl<const MIN: char, const MAX: char> WrappingChar<MIN, MAX> {
+    // See impl_valid_bounds!, which this extends.
+    const VALID_BOUNDS: () = {
+        assert!(MIN < MAX, "MIN must be less than MAX");
+        assert!(
+            MAX as u32 <= 0xD800 || MIN as u32 >= 0xE000,
+            "the bounds can't span the surrogate code points"
+        );
+    };
+
+    /// Wraps `inner` into range by its code point.
+    pub fn new(inner: char) -> Self { Self::from_offset(inner as i64) }
+    pub fn inner(&self) -> char { self.0 }
+
+    /// The number of code points in range.
+    pub const fn width() -> u32 {
+        let () = Self::VALID_BOUNDS;
+        MAX as u32 - MIN as u32
+    }
+
+    /// Moves forwards (or backwards, if `n` is negative) by `n` code points,
+    /// wrapping around at either end.
+    pub fn shift(self, n: i32) -> Self {
+        Self::from_offset(self.0 as i64 + n as i64)
+    }
+
+    // Wraps any code point, including invalid ones, into range.
+    fn from_offset(code_point: i64) -> Self {
+        let offset = code_point - MIN as i64;
+        let position = offset.rem_euclid(Self::width() as i64) as u32;
+
+        match char::from_u32(MIN as u32 + position) {
+            Some(inner) => Self(inner),
+            None => unreachable!("the bounds exclude surrogates"),
+        }
+    }
+}
+
+impl<const MIN: char, const MAX: char> From<char> for WrappingChar<MIN, MAX> {
+    fn from(inner: char) -> Self { Self::new(inner) }
+}
+
+impl_ord!(WrappingChar<MIN, MAX>, WrappingChar<OTHER_MIN, OTHER_MAX>, char);
+impl_hash!(WrappingChar<MIN, MAX>, char);
+impl_bound_consts!(WrappingChar<MIN, MAX>, char);
+impl_default!(WrappingChar<MIN, MAX>, char);
+impl_display!(WrappingChar<MIN, MAX>, char);
+impl_deref!(WrappingChar<MIN, MAX>, char);
+impl_into_inner!(WrappingChar<MIN, MAX>, char);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Lowercase = WrappingChar<'a', '{'>;
+
+    #[test]
+    fn shift_wraps_around() {
+        assert_eq!(Lowercase::new('z').shift(1), 'a');
+        assert_eq!(Lowercase::new('x').shift(3), 'a');
+        assert_eq!(Lowercase::new('a').shift(-1), 'z');
+        assert_eq!(Lowercase::new('c').shift(-29), 'z');
+        assert_eq!(Lowercase::new('m').shift(26 * 1000 + 1), 'n');
+        assert_eq!(Lowercase::new('m').shift(i32::MIN), 'o');
+        assert_eq!(Lowercase::new('q').shift(0), 'q');
+    }
+
+    #[test]
+    fn new_wraps_by_code_point() {
+        assert_eq!(Lowercase::new('{'), 'a');
+        assert_eq!(Lowercase::new('`'), 'z');
+        assert_eq!(Lowercase::new('A'), 'u');
+        assert_eq!(Lowercase::default(), 'a');
+        assert_eq!(Lowercase::width(), 26);
+    }
+
+    #[test]
+    fn bounds_can_be_above_the_surrogates() {
+        type Private = WrappingChar<'\u{E000}', '\u{E010}'>;
+        assert_eq!(Private::new('\u{E00F}').shift(1), '\u{E000}');
+        assert_eq!(Private::new('\u{D7FF}'), '\u{E00F}');
+    }
+
+    #[test]
+    fn into_char() {
+        let c: char = Lowercase::new('q').into();
+        assert_eq!(c, 'q');
+        assert_eq!(Lowercase::new('q').to_string(), "q");
+        assert!(Lowercase::new('b') < Lowercase::new('c'));
+    }
+}
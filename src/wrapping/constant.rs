@@ -0,0 +1,291 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+    SubAssign,
+};
+
+use super::Wrapping;
+use crate::bounds::{Bounds, BoundsProvider};
+use crate::clamped::Clamped;
+
+/// A [`Wrapping`] value whose bounds are supplied by the marker type `B`, so
+/// that it's no larger than `T` itself. This is useful when the bounds are
+/// known at compile time, but `T` can't be used as a const generic parameter.
+///
+/// ```rust
+/// use clamps::wrapping::ConstWrapping;
+/// use clamps::BoundsProvider;
+///
+/// struct Hours;
+/// impl BoundsProvider<i32> for Hours {
+///     fn min() -> i32 { 0 }
+///     fn max() -> i32 { 24 }
+/// }
+///
+/// let mut time = ConstWrapping::<i32, Hours>::new(22);
+/// time += 5;
+/// assert_eq!(time, 3);
+/// assert_eq!(core::mem::size_of_val(&time), core::mem::size_of::<i32>());
+/// ```
+pub struct ConstWrapping<T, B>(T, PhantomData<B>);
+
+impl<
+        T: PartialOrd
+            + Clone
+            + Add<Output = T>
+            + Rem<Output = T>
+            + Sub<Output = T>,
+        B: BoundsProvider<T>,
+    > ConstWrapping<T, B>
+{
+    /// Panics if `B::min()` is not less than `B::max()`.
+    pub fn new(inner: T) -> Self {
+        let wrapping = Wrapping::with_bounds(inner, Self::bounds());
+        Self(wrapping.into_inner(), PhantomData)
+    }
+
+    pub fn bounds() -> Bounds<T> { Bounds::new(B::min(), B::max()) }
+    pub fn contains(value: &T) -> bool { Self::bounds().contains(value) }
+
+    /// Converts into a [`Wrapping`], which stores the bounds alongside the
+    /// value.
+    pub fn into_wrapping(self) -> Wrapping<T> {
+        Wrapping::with_bounds(self.0, Self::bounds())
+    }
+}
+
+impl<T, B> ConstWrapping<T, B> {
+    pub fn inner(&self) -> &T { &self.0 }
+    pub fn into_inner(self) -> T { self.0 }
+}
+
+impl<
+        T: PartialOrd
+            + Clone
+            + Add<Output = T>
+            + Rem<Output = T>
+            + Sub<Output = T>,
+        B: BoundsProvider<T>,
+    > Clamped for ConstWrapping<T, B>
+{
+    type Inner = T;
+    type Output = Self;
+
+    fn inner(&self) -> T { self.0.clone() }
+    fn min_bound(&self) -> T { B::min() }
+    fn max_bound(&self) -> T { B::max() }
+    fn clamp(&self, value: T) -> Self { Self::new(value) }
+}
+
+// arithmetic
+macro_rules! impl_arith {
+    ($trait:ident, $fn:ident, $impl:expr) => {
+        impl<T: $trait, B> $trait<T> for ConstWrapping<T, B> {
+            type Output = T::Output;
+            fn $fn(self, other: T) -> Self::Output { $impl(self.0, other) }
+        }
+
+        impl<T: $trait, B> $trait<ConstWrapping<T, B>> for ConstWrapping<T, B> {
+            type Output = T::Output;
+            fn $fn(self, other: ConstWrapping<T, B>) -> Self::Output {
+                $impl(self.0, other.0)
+            }
+        }
+    };
+}
+
+impl_arith!(Add, add, |this, other| this + other);
+impl_arith!(Sub, sub, |this, other| this - other);
+impl_arith!(Mul, mul, |this, other| this * other);
+impl_arith!(Div, div, |this, other| this / other);
+impl_arith!(Rem, rem, |this, other| this % other);
+
+// assigning arithmetic is delegated to Wrapping, which already knows how to
+// avoid overflowing before it has the chance to wrap
+macro_rules! impl_arith_assign {
+    ($trait:ident, $fn:ident) => {
+        impl<
+                T: PartialOrd
+                    + Copy
+                    + Add<Output = T>
+                    + Sub<Output = T>
+                    + Mul<Output = T>
+                    + Div<Output = T>
+                    + Rem<Output = T>
+                    + $trait,
+                B: BoundsProvider<T>,
+            > $trait<T> for ConstWrapping<T, B>
+        {
+            fn $fn(&mut self, other: T) {
+                let mut wrapping = self.into_wrapping();
+                wrapping.$fn(other);
+                self.0 = wrapping.into_inner();
+            }
+        }
+
+        impl<
+                T: PartialOrd
+                    + Copy
+                    + Add<Output = T>
+                    + Sub<Output = T>
+                    + Mul<Output = T>
+                    + Div<Output = T>
+                    + Rem<Output = T>
+                    + $trait,
+                B: BoundsProvider<T>,
+            > $trait<ConstWrapping<T, B>> for ConstWrapping<T, B>
+        {
+            fn $fn(&mut self, other: ConstWrapping<T, B>) { self.$fn(other.0) }
+        }
+    };
+}
+
+impl_arith_assign!(AddAssign, add_assign);
+impl_arith_assign!(SubAssign, sub_assign);
+impl_arith_assign!(MulAssign, mul_assign);
+impl_arith_assign!(DivAssign, div_assign);
+impl_arith_assign!(RemAssign, rem_assign);
+
+// negation
+impl<
+        T: PartialOrd
+            + Clone
+            + Add<Output = T>
+            + Rem<Output = T>
+            + Sub<Output = T>
+            + Neg<Output = T>,
+        B: BoundsProvider<T>,
+    > Neg for ConstWrapping<T, B>
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output { Self::new(-self.0) }
+}
+
+// comparison
+impl<T: PartialEq, B> PartialEq<T> for ConstWrapping<T, B> {
+    fn eq(&self, other: &T) -> bool { self.0 == *other }
+}
+impl<T: PartialEq, B> PartialEq for ConstWrapping<T, B> {
+    fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
+}
+impl<T: Eq, B> Eq for ConstWrapping<T, B> {}
+
+impl<T: PartialOrd, B> PartialOrd<T> for ConstWrapping<T, B> {
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+impl<T: PartialOrd, B> PartialOrd for ConstWrapping<T, B> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+impl<T: Ord, B> Ord for ConstWrapping<T, B> {
+    fn cmp(&self, other: &Self) -> Ordering { self.0.cmp(&other.0) }
+}
+
+impl<T: Hash, B> Hash for ConstWrapping<T, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.0.hash(state) }
+}
+
+// Display only shows the inner value
+impl<T: fmt::Display, B> fmt::Display for ConstWrapping<T, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: fmt::Debug, B> fmt::Debug for ConstWrapping<T, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstWrapping").field(&self.0).finish()
+    }
+}
+
+// B is only a marker, so it doesn't need to be Clone or Copy itself
+impl<T: Clone, B> Clone for ConstWrapping<T, B> {
+    fn clone(&self) -> Self { Self(self.0.clone(), PhantomData) }
+}
+impl<T: Copy, B> Copy for ConstWrapping<T, B> {}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::size_of;
+
+    use super::*;
+
+    struct Hours;
+    impl BoundsProvider<i32> for Hours {
+        fn min() -> i32 { 0 }
+        fn max() -> i32 { 24 }
+    }
+
+    struct Turn;
+    impl BoundsProvider<f64> for Turn {
+        fn min() -> f64 { -0.5 }
+        fn max() -> f64 { 0.5 }
+    }
+
+    struct Invalid;
+    impl BoundsProvider<i32> for Invalid {
+        fn min() -> i32 { 5 }
+        fn max() -> i32 { 5 }
+    }
+
+    #[test]
+    fn has_no_storage_overhead() {
+        assert_eq!(size_of::<ConstWrapping<i32, Hours>>(), size_of::<i32>());
+        assert_eq!(size_of::<ConstWrapping<f64, Turn>>(), size_of::<f64>());
+        assert!(size_of::<Wrapping<i32>>() > size_of::<i32>());
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(ConstWrapping::<i32, Hours>::new(5), 5);
+        assert_eq!(ConstWrapping::<i32, Hours>::new(24), 0);
+        assert_eq!(ConstWrapping::<i32, Hours>::new(-1), 23);
+        assert_eq!(ConstWrapping::<f64, Turn>::new(0.75), -0.25);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_use_invalid_bounds() { ConstWrapping::<i32, Invalid>::new(5); }
+
+    #[test]
+    fn test_arith() {
+        let foo = ConstWrapping::<i32, Hours>::new(20);
+        assert_eq!(foo + 10, 30);
+        assert_eq!(foo - foo, 0);
+
+        let mut bar = foo;
+        bar += 10;
+        assert_eq!(bar, 6);
+        bar -= 100;
+        assert_eq!(bar, 2);
+        bar *= 13;
+        assert_eq!(bar, 2);
+        bar -= foo;
+        assert_eq!(bar, 6);
+        assert_eq!(-bar, 18);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let foo = ConstWrapping::<i32, Hours>::new(3);
+        assert_eq!(ConstWrapping::<i32, Hours>::bounds(), Bounds::new(0, 24));
+        assert!(ConstWrapping::<i32, Hours>::contains(&23));
+        assert!(!ConstWrapping::<i32, Hours>::contains(&24));
+        assert_eq!(Clamped::max_bound(&foo), 24);
+        assert_eq!(Clamped::clamp(&foo, 25), 1);
+        assert_eq!(foo.into_wrapping(), Wrapping::new(3, 0, 24));
+    }
+
+    #[test]
+    fn test_fmt() {
+        let foo = ConstWrapping::<i32, Hours>::new(3);
+        assert_eq!(format!("{}", foo), "3");
+        assert_eq!(format!("{:?}", foo), "ConstWrapping(3)");
+    }
+}
@@ -0,0 +1,83 @@
+use super::WrappingUSize;
+
+/// A selection index that wraps around at either end, such as the highlighted
+/// entry of a menu with `MAX - MIN` items.
+///
+/// ```rust
+/// use clamps::wrapping::Cursor;
+///
+/// let items = ["New", "Open", "Save", "Quit"];
+/// let mut cursor = Cursor::<0, 4>::default();
+///
+/// cursor.prev();
+/// assert_eq!(items[cursor.get()], "Quit");
+/// cursor.next();
+/// cursor.next();
+/// assert_eq!(items[cursor.get()], "Open");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Cursor<const MIN: usize, const MAX: usize>(WrappingUSize<MIN, MAX>);
+
+impl<const MIN: usize, const MAX: usize> Cursor<MIN, MAX> {
+    /// Starts at `index`, wrapped into `MIN..MAX` if needed.
+    pub fn new(index: usize) -> Self { Self(WrappingUSize::from(index)) }
+
+    pub fn get(&self) -> usize { self.0.inner() }
+
+    /// Moves forward by one, wrapping around to `MIN` after `MAX - 1`, and
+    /// returns the new index.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> usize {
+        // Wrapping around is expected here, so this shouldn't trip the
+        // strict-debug checks in wrapping_add().
+        self.0 = self.0.overflowing_add(1).0;
+        self.get()
+    }
+
+    /// Moves back by one, wrapping around to `MAX - 1` before `MIN`, and
+    /// returns the new index.
+    pub fn prev(&mut self) -> usize {
+        self.0 = self.0.overflowing_sub(1).0;
+        self.get()
+    }
+
+    pub fn into_wrapping(self) -> WrappingUSize<MIN, MAX> { self.0 }
+}
+
+impl<const MIN: usize, const MAX: usize> From<WrappingUSize<MIN, MAX>>
+    for Cursor<MIN, MAX>
+{
+    fn from(value: WrappingUSize<MIN, MAX>) -> Self { Self(value) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let mut cursor = Cursor::<0, 3>::default();
+        assert_eq!(cursor.get(), 0);
+        assert_eq!(cursor.next(), 1);
+        assert_eq!(cursor.next(), 2);
+        assert_eq!(cursor.next(), 0);
+        assert_eq!(cursor.get(), 0);
+    }
+
+    #[test]
+    fn test_prev() {
+        let mut cursor = Cursor::<2, 5>::new(3);
+        assert_eq!(cursor.prev(), 2);
+        assert_eq!(cursor.prev(), 4);
+        assert_eq!(cursor.prev(), 3);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Cursor::<2, 5>::default().get(), 2);
+        assert_eq!(Cursor::<2, 5>::new(6).get(), 3);
+
+        let cursor = Cursor::from(WrappingUSize::<0, 10>::from(7));
+        assert_eq!(cursor.into_wrapping(), 7);
+    }
+}
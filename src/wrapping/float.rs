@@ -0,0 +1,158 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Range, Rem, RemAssign,
+    Sub, SubAssign,
+};
+
+use crate::clamped::Clamped;
+use crate::float::FloatBounds;
+use crate::macros::*;
+
+macro_rules! impl_all {
+    ($type:ident, $inner:ty) => {
+        impl<B: FloatBounds<$inner>> $type<B> {
+            pub fn new(inner: $inner) -> Self { Self::from(inner) }
+            pub fn range(&self) -> Range<$inner> { B::MIN..B::MAX }
+        }
+
+        impl<B: FloatBounds<$inner>> From<$inner> for $type<B> {
+            /// NaN and infinite values can't be wrapped, so they become `MIN`.
+            fn from(inner: $inner) -> Self {
+                assert!(B::MIN < B::MAX, "MIN must be less than MAX");
+
+                let width = B::MAX - B::MIN;
+                let mut rem = (inner - B::MIN) % width;
+                if rem < 0.0 {
+                    rem += width;
+                }
+
+                // This also catches NaN (which can't be compared), and the
+                // rare case where rounding leaves us sitting on MAX.
+                let result = B::MIN + rem;
+                if result >= B::MIN && result < B::MAX {
+                    Self(result, PhantomData)
+                } else {
+                    Self(B::MIN, PhantomData)
+                }
+            }
+        }
+
+        impl<B: FloatBounds<$inner>> Neg for $type<B> {
+            type Output = Self;
+            fn neg(self) -> Self::Output { Self::from(-self.0) }
+        }
+
+        impl_float!($type, $inner);
+        impl_clamped_float!($type, $inner, Self, Self::from);
+        impl_float_arith_assign!($type, $inner);
+
+        #[cfg(feature = "serde")]
+        impl_serde_float!($type, $inner, |inner| Ok(Self::from(inner)));
+    };
+}
+
+pub struct WrappingF32<B>(f32, PhantomData<B>);
+impl_all!(WrappingF32, f32);
+
+pub struct WrappingF64<B>(f64, PhantomData<B>);
+impl_all!(WrappingF64, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Degrees;
+    impl FloatBounds<f32> for Degrees {
+        const MIN: f32 = 0.0;
+        const MAX: f32 = 360.0;
+    }
+    impl FloatBounds<f64> for Degrees {
+        const MIN: f64 = 0.0;
+        const MAX: f64 = 360.0;
+    }
+
+    struct Phase;
+    impl FloatBounds<f64> for Phase {
+        const MIN: f64 = -0.5;
+        const MAX: f64 = 0.5;
+    }
+
+    struct Invalid;
+    impl FloatBounds<f64> for Invalid {
+        const MIN: f64 = 1.0;
+        const MAX: f64 = -1.0;
+    }
+
+    #[test]
+    fn can_create() {
+        let foo = WrappingF32::<Degrees>::from(90.0);
+        assert_eq!(foo.inner(), 90.0);
+        assert_eq!(foo.range(), 0.0..360.0);
+    }
+
+    #[test]
+    fn will_wrap() {
+        assert_eq!(WrappingF32::<Degrees>::from(360.0), 0.0);
+        assert_eq!(WrappingF32::<Degrees>::from(450.0), 90.0);
+        assert_eq!(WrappingF32::<Degrees>::from(-90.0), 270.0);
+        assert_eq!(WrappingF32::<Degrees>::from(-720.0), 0.0);
+        assert_eq!(WrappingF64::<Phase>::from(0.75), -0.25);
+        assert_eq!(WrappingF64::<Phase>::from(-0.75), 0.25);
+    }
+
+    #[test]
+    fn non_finite_wraps_to_min() {
+        assert_eq!(WrappingF32::<Degrees>::from(f32::NAN), 0.0);
+        assert_eq!(WrappingF32::<Degrees>::from(f32::INFINITY), 0.0);
+        assert_eq!(WrappingF64::<Phase>::from(f64::NEG_INFINITY), -0.5);
+    }
+
+    #[test]
+    fn test_arith() {
+        let mut foo = WrappingF64::<Degrees>::from(300.0);
+        assert_eq!(foo + 90.0, 390.0);
+
+        foo += 90.0;
+        assert_eq!(foo, 30.0);
+        foo -= 60.0;
+        assert_eq!(foo, 330.0);
+        foo *= 2.0;
+        assert_eq!(foo, 300.0);
+        assert_eq!(-foo, 60.0);
+
+        let bar = WrappingF64::<Phase>::from(0.25);
+        foo += bar;
+        assert_eq!(foo, 300.25);
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let a = WrappingF64::<Degrees>::from(450.0);
+        assert_eq!(a.into_inner(), 90.0);
+        assert_eq!(f64::from(a), 90.0);
+    }
+
+    #[test]
+    fn test_default_and_display() {
+        assert_eq!(WrappingF64::<Phase>::default(), -0.5);
+        let foo = WrappingF64::<Degrees>::from(450.5);
+        assert_eq!(format!("{}", foo), "90.5");
+        assert_eq!(format!("{:?}", foo), "WrappingF64(90.5)");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let foo: WrappingF64<Degrees> = serde_json::from_str("370.0").unwrap();
+        assert_eq!(foo, 10.0);
+        assert_eq!(serde_json::to_string(&foo).unwrap(), "10.0");
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_use_invalid_bounds() {
+        let _ = WrappingF64::<Invalid>::from(0.0);
+    }
+}
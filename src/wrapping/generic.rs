@@ -1,13 +1,18 @@
-use std::fmt;
-use std::ops::{
-    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Range, Rem, RemAssign, Sub,
-    SubAssign,
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Range, Rem, RemAssign,
+    Sub, SubAssign,
 };
 
+use crate::bounded::BoundsError;
+use crate::bounds::{Bounds, Integer};
+use crate::builder::Builder;
+use crate::clamped::Clamped;
+
 pub struct Wrapping<T> {
-    inner: T,
-    min:   T,
-    max:   T,
+    inner:  T,
+    bounds: Bounds<T>,
 }
 
 impl<
@@ -18,29 +23,142 @@ impl<
             + Sub<Output = T>,
     > Wrapping<T>
 {
-    pub fn new(mut inner: T, min: T, max: T) -> Self {
-        if min >= max {
-            panic!("MIN value must be less than MAX")
+    /// Panics if `min` is not less than `max`. See [`Wrapping::try_new`] for a
+    /// fallible alternative.
+    pub fn new(inner: T, min: T, max: T) -> Self {
+        match Self::try_new(inner, min, max) {
+            Ok(wrapping) => wrapping,
+            Err(_) => panic!("MIN value must be less than MAX"),
         }
+    }
 
-        // TODO: Reduce the number of clones needed here
-        if inner >= max {
-            let rem = (inner - min.clone()) % (max.clone() - min.clone());
-            inner = min.clone() + rem;
-        } else if inner < min {
-            let rem =
-                (inner.clone() + min.clone()) % (max.clone() - min.clone());
+    pub fn try_new(inner: T, min: T, max: T) -> Result<Self, BoundsError> {
+        Ok(Self::with_bounds(inner, Bounds::try_new(min, max)?))
+    }
+
+    /// Takes the bounds from `min..max`. An empty range is rejected with
+    /// [`BoundsError::InvalidBounds`].
+    pub fn from_range(inner: T, range: Range<T>) -> Result<Self, BoundsError> {
+        Self::try_new(inner, range.start, range.end)
+    }
+
+    pub fn with_bounds(mut inner: T, bounds: Bounds<T>) -> Self {
+        let Bounds { min, max } = &bounds;
+
+        // Values that are already in range aren't cloned at all. Otherwise, as
+        // the operators take T by value and the bounds need to be kept, four
+        // clones are needed: two for the width, and one for each time a bound
+        // is used on either side of the remainder.
+        if inner >= *max {
+            let rem = (inner - min.clone()) % bounds.width();
             inner = min.clone() + rem;
+        } else if inner < *min {
+            let rem = (min.clone() - inner) % bounds.width();
+            inner = max.clone() - rem;
+
+            // a remainder of zero would otherwise leave us sitting on MAX,
+            // which is the only case that needs a fifth clone
+            if inner >= *max {
+                inner = min.clone();
+            }
         }
 
-        Self { inner, max, min }
+        Self { inner, bounds }
+    }
+
+    /// Returns a [`Builder`], which names each argument explicitly.
+    pub fn builder() -> Builder<T, Self> {
+        Builder::new(|inner, bounds| Ok(Self::with_bounds(inner, bounds)))
     }
 
     pub fn inner(&self) -> &T { &self.inner }
     pub fn into_inner(self) -> T { self.inner }
-    pub fn range(&self) -> Range<&T> { &self.min..&self.max }
-    pub fn min_bound(&self) -> &T { &self.min }
-    pub fn max_bound(&self) -> &T { &self.max }
+    pub fn bounds(&self) -> &Bounds<T> { &self.bounds }
+    pub fn contains(&self, value: &T) -> bool { self.bounds.contains(value) }
+    pub fn range(&self) -> Range<&T> { self.bounds.range() }
+    pub fn min_bound(&self) -> &T { &self.bounds.min }
+    pub fn max_bound(&self) -> &T { &self.bounds.max }
+}
+
+impl<
+        T: PartialOrd
+            + Clone
+            + Add<Output = T>
+            + Rem<Output = T>
+            + Sub<Output = T>
+            + Integer,
+    > Wrapping<T>
+{
+    /// Wraps into `min..=max`, so that `max` itself is reachable and only
+    /// `max + 1` wraps back around to `min`. This is stored as `min..max + 1`,
+    /// which is what [`Wrapping::max_bound`] will return. Only integer types
+    /// are supported.
+    ///
+    /// Panics if `min` is greater than `max`, or if `max + 1` overflows.
+    pub fn new_inclusive(inner: T, min: T, max: T) -> Self {
+        Self::new(inner, min, max + T::ONE)
+    }
+}
+
+impl Wrapping<usize> {
+    /// An index into `slice`, wrapped into `0..slice.len()`. An empty slice
+    /// has no valid indices, so it's rejected with
+    /// [`BoundsError::InvalidBounds`].
+    pub fn for_slice<E>(
+        index: usize, slice: &[E],
+    ) -> Result<Self, BoundsError> {
+        Self::try_new(index, 0, slice.len())
+    }
+}
+
+impl<T: PartialOrd> Wrapping<T> {
+    /// Returns whichever value is larger, or `other` if they're equal.
+    pub fn max_with(self, other: Self) -> Self {
+        if self.inner > other.inner {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns whichever value is smaller, or `self` if they're equal.
+    pub fn min_with(self, other: Self) -> Self {
+        if other.inner < self.inner {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<
+        T: PartialOrd
+            + Clone
+            + Add<Output = T>
+            + Rem<Output = T>
+            + Sub<Output = T>,
+    > Clamped for Wrapping<T>
+{
+    type Inner = T;
+    type Output = Self;
+
+    fn inner(&self) -> T { self.inner.clone() }
+    fn min_bound(&self) -> T { self.bounds.min.clone() }
+    fn max_bound(&self) -> T { self.bounds.max.clone() }
+    fn clamp(&self, value: T) -> Self {
+        Wrapping::with_bounds(value, self.bounds.clone())
+    }
+}
+
+impl<
+        T: PartialOrd
+            + Clone
+            + Add<Output = T>
+            + Rem<Output = T>
+            + Sub<Output = T>,
+    > crate::Clamp<T> for Wrapping<T>
+{
+    fn clamp(&self, value: T) -> T { Clamped::clamp(self, value).inner }
 }
 
 //arithmetic
@@ -81,7 +199,7 @@ macro_rules! impl_arith_assign {
             > $trait<T> for Wrapping<T>
         {
             fn $fn(&mut self, other: T) {
-                *self = Wrapping::new($impl(*self, other), self.min, self.max)
+                *self = Wrapping::with_bounds($impl(*self, other), self.bounds)
             }
         }
 
@@ -97,7 +215,7 @@ macro_rules! impl_arith_assign {
             > $trait<Wrapping<T>> for Wrapping<T>
         {
             fn $fn(&mut self, other: Wrapping<T>) {
-                *self = Wrapping::new($impl(*self, other), self.min, self.max)
+                *self = Wrapping::with_bounds($impl(*self, other), self.bounds)
             }
         }
     };
@@ -111,14 +229,17 @@ impl_arith_assign!(RemAssign, rem_assign, |this, other| this % other);
 fn wrapping_sub<
     T: Copy + PartialOrd + Sub<Output = T> + Add<Output = T> + Rem<Output = T>,
 >(
-    this: Wrapping<T>, mut other: T,
+    this: Wrapping<T>, other: T,
 ) -> T {
-    if other > this.inner {
-        let rem = (other + this.min) % (this.max - this.min);
-        other = this.min + rem;
-        if other > this.inner {
-            return this.inner + this.max - other;
+    // if we'd go below MIN, there's a chance of underflowing before we get
+    // the chance to wrap, so it has to be handled in advance
+    let offset = this.inner - this.bounds.min;
+    if other > offset {
+        let rem = other % (this.bounds.max - this.bounds.min);
+        if rem > offset {
+            return offset + (this.bounds.max - rem);
         }
+        return this.inner - rem;
     }
     this.inner - other
 }
@@ -135,7 +256,7 @@ impl<
 {
     fn sub_assign(&mut self, other: T) {
         let result = wrapping_sub(*self, other);
-        *self = Wrapping::new(result, self.min, self.max)
+        *self = Wrapping::with_bounds(result, self.bounds)
     }
 }
 
@@ -154,6 +275,73 @@ impl<
     }
 }
 
+// Like the assigning operators, but returning a new value with the same bounds
+// rather than modifying this one
+impl<
+        T: PartialOrd
+            + Copy
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Rem<Output = T>,
+    > Wrapping<T>
+{
+    /// Multiplies by `factor`, wrapping the result into the same bounds.
+    pub fn scaled(&self, factor: T) -> Self {
+        Wrapping::with_bounds(self.inner * factor, self.bounds)
+    }
+
+    /// Adds `delta`, wrapping the result into the same bounds.
+    pub fn offset(&self, delta: T) -> Self {
+        Wrapping::with_bounds(self.inner + delta, self.bounds)
+    }
+
+    /// Subtracts `delta`, wrapping the result into the same bounds.
+    pub fn shifted_down(&self, delta: T) -> Self {
+        Wrapping::with_bounds(wrapping_sub(*self, delta), self.bounds)
+    }
+}
+
+// The same names as the concrete types' methods, which (unlike the operators)
+// keep the result wrapped in the same bounds. Most of these are the same as
+// the methods above, but take self by value.
+impl<
+        T: PartialOrd
+            + Copy
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>,
+    > Wrapping<T>
+{
+    pub fn wrapping_add(self, rhs: T) -> Self { self.offset(rhs) }
+    pub fn wrapping_sub(self, rhs: T) -> Self { self.shifted_down(rhs) }
+    pub fn wrapping_mul(self, rhs: T) -> Self { self.scaled(rhs) }
+    pub fn wrapping_div(self, rhs: T) -> Self {
+        Wrapping::with_bounds(self.inner / rhs, self.bounds)
+    }
+    pub fn wrapping_rem(self, rhs: T) -> Self {
+        Wrapping::with_bounds(self.inner % rhs, self.bounds)
+    }
+}
+
+// negation
+impl<
+        T: PartialOrd
+            + Clone
+            + Add<Output = T>
+            + Rem<Output = T>
+            + Sub<Output = T>
+            + Neg<Output = T>,
+    > Neg for Wrapping<T>
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Wrapping::with_bounds(-self.inner, self.bounds)
+    }
+}
+
 // equality
 impl<T: PartialEq> PartialEq<T> for Wrapping<T> {
     fn eq(&self, other: &T) -> bool { self.inner == *other }
@@ -163,13 +351,25 @@ impl<T: PartialEq> PartialEq<Wrapping<T>> for Wrapping<T> {
 }
 impl<T: Eq> Eq for Wrapping<T> {}
 
+// hashing has to agree with equality, so the bounds are ignored
+impl<T: Hash> Hash for Wrapping<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.inner.hash(state) }
+}
+
+// Display only shows the inner value
+impl<T: fmt::Display> fmt::Display for Wrapping<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
 // We can only implement Debug if T implements Debug
 impl<T: fmt::Debug> fmt::Debug for Wrapping<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "Wrapping {{inner: {:?}, min: {:?}, max: {:?}}}",
-            self.inner, self.min, self.max
+            self.inner, self.bounds.min, self.bounds.max
         )
     }
 }
@@ -178,18 +378,54 @@ impl<T: fmt::Debug> fmt::Debug for Wrapping<T> {
 impl<T: Clone> Clone for Wrapping<T> {
     fn clone(&self) -> Self {
         Self {
-            inner: self.inner.clone(),
-            min:   self.min.clone(),
-            max:   self.max.clone(),
+            inner:  self.inner.clone(),
+            bounds: self.bounds.clone(),
         }
     }
 }
 impl<T: Copy> Copy for Wrapping<T> {}
 
+#[cfg(feature = "serde")]
+crate::macros::impl_serde_generic!(
+    Wrapping,
+    [PartialOrd + Clone + Add<Output = T> + Rem<Output = T> + Sub<Output = T>],
+    |inner, min, max| Wrapping::try_new(inner, min, max)
+        .map_err(serde::de::Error::custom)
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn inclusive_reaches_the_top_value() {
+        // the top value is wrapped when exclusive, but not when inclusive
+        assert_eq!(Wrapping::new(10, 0, 10), 0);
+        assert_eq!(Wrapping::new_inclusive(10, 0, 10), 10);
+        assert_eq!(Wrapping::new(11, 0, 10), 1);
+        assert_eq!(Wrapping::new_inclusive(11, 0, 10), 0);
+        assert_eq!(Wrapping::new(-1, 0, 10), 9);
+        assert_eq!(Wrapping::new_inclusive(-1, 0, 10), 10);
+
+        let mut foo = Wrapping::new_inclusive(9i8, -3, 10);
+        foo += 1;
+        assert_eq!(foo, 10);
+        foo += 1;
+        assert_eq!(foo, -3);
+        foo -= 1;
+        assert_eq!(foo, 10);
+        assert_eq!(foo.range(), &-3..&11);
+
+        // a single value is allowed, as the width is still one
+        assert_eq!(Wrapping::new_inclusive(7u8, 4, 4), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inclusive_rejects_reversed_bounds() {
+        Wrapping::new_inclusive(0u32, 5, 4);
+    }
+
     #[test]
     fn test_create() {
         let foo = Wrapping::new(3, -5, 74);
@@ -198,11 +434,99 @@ mod tests {
 
     #[test]
     fn test_debug() {
-        let foo = Wrapping { inner: 3, min: -5, max: 74 };
+        let foo = Wrapping::new(3, -5, 74);
         let out = format!("{:?}", foo);
         assert_eq!(&out, "Wrapping {inner: 3, min: -5, max: 74}")
     }
 
+    #[test]
+    fn test_contains() {
+        let foo = Wrapping::new(3, -2, 8);
+        assert!(foo.contains(&-2));
+        assert!(foo.contains(&7));
+        assert!(!foo.contains(&8));
+        assert!(!foo.contains(&-3));
+    }
+
+    #[test]
+    fn for_slice() {
+        let items = ["a", "b", "c"];
+        let index = Wrapping::for_slice(3, &items).unwrap();
+        assert_eq!(index, 0);
+        assert_eq!(items[index.into_inner()], "a");
+        assert_eq!(Wrapping::for_slice(7, &items).unwrap(), 1);
+        assert_eq!(Wrapping::for_slice(2, &items).unwrap() + 1, 3);
+
+        let empty: [u8; 0] = [];
+        let result = Wrapping::for_slice(0, &empty);
+        assert_eq!(result, Err(BoundsError::InvalidBounds));
+    }
+
+    #[test]
+    fn with_bounds_clones_sparingly() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        // An i32 that counts how many times it (or anything derived from it)
+        // has been cloned.
+        #[derive(PartialEq, PartialOrd)]
+        struct Counted(i32, Rc<Cell<usize>>);
+
+        impl Clone for Counted {
+            fn clone(&self) -> Self {
+                self.1.set(self.1.get() + 1);
+                Self(self.0, self.1.clone())
+            }
+        }
+
+        macro_rules! impl_op {
+            ($trait:ident, $fn:ident, $op:tt) => {
+                impl $trait for Counted {
+                    type Output = Self;
+                    fn $fn(self, rhs: Self) -> Self {
+                        Self(self.0 $op rhs.0, self.1)
+                    }
+                }
+            };
+        }
+        impl_op!(Add, add, +);
+        impl_op!(Sub, sub, -);
+        impl_op!(Rem, rem, %);
+
+        let clones = |inner: i32| {
+            let count = Rc::new(Cell::new(0));
+            let new = |value| Counted(value, count.clone());
+            let wrapping = Wrapping::new(new(inner), new(-5), new(10));
+            (wrapping.into_inner().0, count.get())
+        };
+
+        assert_eq!(clones(3), (3, 0));
+        assert_eq!(clones(-5), (-5, 0));
+        assert_eq!(clones(12), (-3, 4));
+        assert_eq!(clones(-7), (8, 4));
+        assert_eq!(clones(-20), (-5, 5));
+    }
+
+    #[test]
+    fn test_with_bounds() {
+        let bounds = Bounds::new(0, 10);
+        let foo = Wrapping::with_bounds(23, bounds);
+        assert_eq!(foo, 3);
+        assert_eq!(foo.bounds(), &bounds);
+        assert_eq!(Wrapping::with_bounds(-1, bounds), 9);
+    }
+
+    #[test]
+    fn test_from_range() {
+        let foo = Wrapping::from_range(12, 0..10).unwrap();
+        assert_eq!(foo, 2);
+        assert_eq!(foo.bounds(), &Bounds::new(0, 10));
+        assert_eq!(foo.range(), &0..&10);
+
+        let empty = Wrapping::from_range(5, 5..5);
+        assert_eq!(empty, Err(BoundsError::InvalidBounds));
+    }
+
     #[test]
     fn test_max_and_min() {
         let foo = Wrapping::new(4, -3, 8);
@@ -213,8 +537,8 @@ mod tests {
 
     #[test]
     fn test_eq() {
-        let foo = Wrapping { inner: 3, min: -5, max: 74 };
-        let bar = Wrapping { inner: 3, min: 0, max: 74 };
+        let foo = Wrapping::new(3, -5, 74);
+        let bar = Wrapping::new(3, 0, 74);
         assert_eq!(foo, 3);
         assert_eq!(bar, 3);
         assert_eq!(foo, bar);
@@ -236,6 +560,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn wrapping_methods_return_self() {
+        let a = Wrapping::new(5, 2, 8);
+        let b: Wrapping<i32> = a.wrapping_add(5);
+        assert_eq!(b, 4);
+        assert_eq!(b.bounds(), a.bounds());
+
+        let c = a.wrapping_add(5).wrapping_mul(3).wrapping_sub(4);
+        assert_eq!(c, 2);
+        assert_eq!(c.wrapping_sub(20).wrapping_div(2), 3);
+        assert_eq!(a.wrapping_rem(3).wrapping_sub(1), 7);
+    }
+
+    #[test]
+    fn test_scaled_and_offset() {
+        let foo = Wrapping::new(0.75, 0.0, 1.0);
+        assert_eq!(foo.scaled(2.0), 0.5);
+        assert_eq!(foo.offset(0.5), 0.25);
+        assert_eq!(foo.shifted_down(1.0), 0.75);
+        assert_eq!(foo.shifted_down(0.25), 0.5);
+
+        let bar = foo.scaled(3.0).offset(0.5);
+        assert_eq!(bar, 0.75);
+        assert_eq!(bar.bounds(), foo.bounds());
+        assert_eq!(foo, 0.75);
+
+        // unsigned values don't underflow before wrapping
+        assert_eq!(Wrapping::new(2u8, 1, 5).shifted_down(10), 4);
+    }
+
     #[test]
     fn test_wrapping() {
         let mut foo = Wrapping::new(0.0, 0.0, 10.0);
@@ -255,4 +609,138 @@ mod tests {
             assert_eq!(foo, 9 - (i % 10));
         }
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let foo = Wrapping::new(3, -5, 74);
+        let json = serde_json::to_string(&foo).unwrap();
+        assert_eq!(json, r#"{"inner":3,"min":-5,"max":74}"#);
+        let bar: Wrapping<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bar, foo);
+        assert_eq!(bar.range(), foo.range());
+
+        let foo = Wrapping::new(2.5, 0.0, 10.0);
+        let json = serde_json::to_string(&foo).unwrap();
+        let bar: Wrapping<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bar, foo);
+        assert_eq!(bar.range(), foo.range());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_deserialize_will_wrap() {
+        let json = r#"{"inner":12,"min":0,"max":10}"#;
+        let foo: Wrapping<u32> = serde_json::from_str(json).unwrap();
+        assert_eq!(foo, 2);
+
+        let json = r#"{"inner":3,"min":10,"max":0}"#;
+        assert!(serde_json::from_str::<Wrapping<u32>>(json).is_err());
+    }
+
+    #[test]
+    fn test_hash() {
+        use crate::test_utils::hash_of;
+
+        let foo = Wrapping::new(3, -5, 74);
+        let bar = Wrapping::new(3, 0, 74);
+        assert_eq!(foo, bar);
+        assert_eq!(hash_of(&foo), hash_of(&bar));
+    }
+
+    #[test]
+    fn test_display() {
+        let foo = Wrapping::new(3.5, -5.0, 74.0);
+        assert_eq!(format!("{}", foo), "3.5");
+        assert_eq!(format!("{:.2}", foo), "3.50");
+    }
+
+    #[test]
+    fn test_neg() {
+        let foo = Wrapping::new(40, -100, 50);
+        assert_eq!(-foo, -40);
+
+        let bar = Wrapping::new(-80.0, -100.0, 50.0);
+        assert_eq!(-bar, -70.0);
+        assert_eq!((-bar).range(), bar.range());
+    }
+
+    #[test]
+    fn test_wrapping_at_boundaries() {
+        assert_eq!(Wrapping::new(10, 0, 10), 0);
+        assert_eq!(Wrapping::new(11, 0, 10), 1);
+        assert_eq!(Wrapping::new(-1, 0, 10), 9);
+        assert_eq!(Wrapping::new(10.0, 0.0, 10.0), 0.0);
+    }
+
+    #[test]
+    fn test_wrapping_below_min() {
+        assert_eq!(Wrapping::new(-3, 0, 10), 7);
+        assert_eq!(Wrapping::new(-10, 0, 10), 0);
+        assert_eq!(Wrapping::new(3, 5, 9), 7);
+        assert_eq!(Wrapping::new(-2.5, 0.0, 10.0), 7.5);
+    }
+
+    #[test]
+    fn test_wrapping_backward_nonzero_min() {
+        let mut foo = Wrapping::<u32>::new(6, 4, 8);
+        foo -= 3;
+        assert_eq!(foo, 7);
+        foo -= 11;
+        assert_eq!(foo, 4);
+        foo -= 1;
+        assert_eq!(foo, 7);
+    }
+
+    #[test]
+    fn test_matches_concrete() {
+        use crate::wrapping::WrappingI32;
+
+        for inner in -100..100 {
+            let generic = Wrapping::new(inner, -5, 10);
+            let concrete = WrappingI32::<-5, 10>::new(inner);
+            assert_eq!(generic, concrete.inner());
+
+            let generic = Wrapping::new(inner, 3, 7);
+            let concrete = WrappingI32::<3, 7>::new(inner);
+            assert_eq!(generic, concrete.inner());
+        }
+    }
+
+    #[test]
+    fn test_matches_reference_across_zero() {
+        use crate::wrapping::WrappingI32;
+
+        fn reference(inner: i32, min: i32, max: i32) -> i32 {
+            min + (inner - min).rem_euclid(max - min)
+        }
+
+        // five widths past either bound
+        for inner in -110..=110 {
+            let expected = reference(inner, -10, 10);
+            assert_eq!(Wrapping::new(inner, -10, 10), expected);
+            assert_eq!(WrappingI32::<-10, 10>::new(inner), expected);
+
+            let expected = reference(inner, -3, 17);
+            assert_eq!(Wrapping::new(inner, -3, 17), expected);
+            assert_eq!(WrappingI32::<-3, 17>::new(inner), expected);
+        }
+
+        assert_eq!(WrappingI32::<-10, 10>::new(15), -5);
+        assert_eq!(WrappingI32::<-10, 10>::new(-15), 5);
+        assert_eq!(Wrapping::new(15, -10, 10), -5);
+        assert_eq!(Wrapping::new(-15, -10, 10), 5);
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Wrapping::try_new(3, -5, 74).unwrap(), 3);
+        assert_eq!(Wrapping::try_new(3, 5, 5), Err(BoundsError::InvalidBounds));
+        assert_eq!(Wrapping::try_new(3, 10, 5), Err(BoundsError::InvalidBounds));
+        assert!(Wrapping::try_new(0.0, 1.0, -1.0).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_use_invalid_bounds() { let _ = Wrapping::new(3, 10, 5); }
 }
@@ -1,121 +1,658 @@
-use std::cmp::{Ord, Ordering, PartialOrd};
-use std::ops::{
-    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Range, Rem, RemAssign, Sub,
-    SubAssign,
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{self, Product, Sum};
+use core::num::ParseIntError;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor,
+    BitXorAssign, Deref, Div, DivAssign, Mul, MulAssign, Neg, Not, Range, Rem,
+    RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
+use core::str::FromStr;
 
+use crate::clamped::Clamped;
+use crate::guard::ClampGuard;
+use crate::iter::{All, Steps};
 use crate::macros::*;
 
+// Wraps a value into the `width` values starting at `min`, with a function for
+// each inner type. This is shared by the concrete types (using their const
+// bounds) and clamp!() (using the bounds of a range), so that they agree.
+pub(crate) mod wrap {
+    macro_rules! impl_wrap {
+        ($($inner:ident, $uinner:ty);* $(;)?) => {$(
+            pub(crate) const fn $inner(
+                value: $inner, min: $inner, width: $uinner,
+            ) -> $inner {
+                if value < min {
+                    match min.abs_diff(value) % width {
+                        0 => min,
+                        rem => min.wrapping_add((width - rem) as $inner),
+                    }
+                } else if value.abs_diff(min) >= width {
+                    let rem = value.abs_diff(min) % width;
+                    min.wrapping_add(rem as $inner)
+                } else {
+                    value
+                }
+            }
+        )*};
+    }
+
+    impl_wrap!(
+        u8, u8; u16, u16; u32, u32; u64, u64; u128, u128; usize, usize;
+        i8, u8; i16, u16; i32, u32; i64, u64; i128, u128; isize, usize;
+    );
+}
+
 macro_rules! impl_create {
-    ($type:ty, $inner:ty) => {
+    ($type:ty, $inner:ident, $uinner:ty) => {
         impl<const MIN: $inner, const MAX: $inner> $type {
             pub fn new(inner: $inner) -> Self { Self::from(inner) }
             pub fn inner(&self) -> $inner { self.0 }
+
+            /// A `const` version of [`Self::new`], for use in `const` and
+            /// `static` items.
+            pub const fn new_const(inner: $inner) -> Self {
+                let () = Self::VALID_BOUNDS;
+                Self(Self::wrap(inner))
+            }
+
+            /// A `const` version of [`Self::inner`].
+            pub const fn inner_const(&self) -> $inner { self.0 }
             pub fn range(&self) -> Range<$inner> { MIN..MAX }
             pub fn min_bound(&self) -> $inner { MIN }
             pub fn max_bound(&self) -> $inner { MAX }
+
+            // MAX itself is never reached, so there's no is_at_max().
+            pub fn is_at_min(&self) -> bool { self.0 == MIN }
+
+            /// Whether `inner` is already in range, and so would be left
+            /// unchanged by [`From::from`].
+            pub fn contains(inner: $inner) -> bool {
+                inner >= MIN && inner < MAX
+            }
+
+            /// An alias for [`From::from`].
+            pub fn clamp(inner: $inner) -> Self { Self::from(inner) }
+
+            /// Returns an iterator over every value in `MIN..MAX`.
+            pub fn all() -> All<Self> {
+                let () = Self::VALID_BOUNDS;
+                let last = MAX.abs_diff(MIN) as u128 - 1;
+                All::new(last, |offset| {
+                    Self(MIN.wrapping_add(offset as $inner))
+                })
+            }
         }
 
-        impl<const MIN: $inner, const MAX: $inner> From<$inner> for $type {
-            fn from(mut inner: $inner) -> Self {
-                assert!(MIN < MAX, "MIN must be less than MAX");
-
-                if inner >= MAX {
-                    let rem = (inner - MIN) % (MAX - MIN);
-                    inner = MIN + rem;
-                } else if inner < MIN {
-                    let rem = (inner + MIN) % (MAX - MIN);
-                    inner = MIN + rem;
+        // Modular arithmetic is done on each value's position (its offset from
+        // MIN). These are stored in the unsigned form of $inner, which can
+        // always hold MAX - MIN without overflowing.
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            // Wraps any value into MIN..MAX.
+            const fn wrap(inner: $inner) -> $inner {
+                wrap::$inner(inner, MIN, Self::width())
+            }
+
+            const fn position(&self) -> $uinner { self.0.abs_diff(MIN) }
+            const fn from_position(position: $uinner) -> Self {
+                Self(MIN.wrapping_add(position as $inner))
+            }
+
+            // The position at which zero would sit, were the range to repeat
+            // infinitely in each direction.
+            const fn zero_position() -> $uinner {
+                Self(Self::wrap(0)).position()
+            }
+
+            // Returns (value mod width), where the result is in 0..width.
+            const fn residue(value: $inner) -> $uinner {
+                let value = Self(Self::wrap(value)).position();
+                Self::sub_mod(value, Self::zero_position())
+            }
+            const fn from_residue(residue: $uinner) -> Self {
+                let position = Self::add_mod(residue, Self::zero_position());
+                Self::from_position(position)
+            }
+
+            // Addition, subtraction, and multiplication modulo the width. All
+            // arguments must be in 0..width.
+            const fn add_mod(a: $uinner, b: $uinner) -> $uinner {
+                let gap = Self::width() - b;
+                if a >= gap {
+                    a - gap
+                } else {
+                    a + b
+                }
+            }
+            const fn sub_mod(a: $uinner, b: $uinner) -> $uinner {
+                if a >= b {
+                    a - b
+                } else {
+                    Self::width() - (b - a)
+                }
+            }
+            const fn mul_mod(mut a: $uinner, mut b: $uinner) -> $uinner {
+                let mut result = 0;
+                while b > 0 {
+                    if b & 1 == 1 {
+                        result = Self::add_mod(result, a);
+                    }
+                    a = Self::add_mod(a, a);
+                    b >>= 1;
                 }
+                result
+            }
+        }
 
-                Self(inner)
+        impl<const MIN: $inner, const MAX: $inner> From<$inner> for $type {
+            fn from(inner: $inner) -> Self {
+                let value = Self::new_const(inner);
+                invariant_debug_assert!(value);
+                value
+            }
+        }
+
+        // core::num::Wrapping is written out in full to avoid confusing it
+        // with this crate's own Wrapping<T>. Note that as MAX is exclusive,
+        // the inner type's largest value is never in range, so these can't
+        // round-trip it even when the bounds are the inner type's extremes.
+        impl<const MIN: $inner, const MAX: $inner>
+            From<core::num::Wrapping<$inner>> for $type
+        {
+            fn from(value: core::num::Wrapping<$inner>) -> Self {
+                Self::from(value.0)
+            }
+        }
+        impl<const MIN: $inner, const MAX: $inner> From<$type>
+            for core::num::Wrapping<$inner>
+        {
+            fn from(value: $type) -> Self { core::num::Wrapping(value.0) }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> FromStr for $type {
+            type Err = ParseIntError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from(s.parse::<$inner>()?))
             }
         }
     };
 }
 
 macro_rules! impl_all {
-    ($type:ty, $other:ty, $inner:ty) => {
-        impl_create!($type, $inner);
+    ($type:ty, $other:ty, $inner:ident, $uinner:ty) => {
+        impl_create!($type, $inner, $uinner);
+        impl_valid_bounds!($type, $inner);
+        impl_width!($type, $inner, $uinner, MAX - 1);
 
-        impl_arith!($type, $other, $inner, Add, add, |this, other| this
-            + other);
         impl_arith!($type, $other, $inner, Div, div, |this, other| this
             / other);
-        impl_arith!($type, $other, $inner, Mul, mul, |this, other| this
-            * other);
         impl_arith!($type, $other, $inner, Rem, rem, |this, other| this
             % other);
 
-        // Sub takes a bit more work, as we have to factor in underflows for
-        // unsigned integers in advance.
-        impl_arith!($type, $other, $inner, Sub, sub, |this, mut other| {
-            if other > this - MIN {
-                other = (other + MIN) % (MAX - MIN);
-                if other > this {
-                    return this + MAX - other;
+        // Like the inner type's own operators, these can overflow (see the
+        // module docs). The wrapping_* methods never do.
+        impl_arith!($type, $other, $inner, Add, add, |this, other| this
+            + other);
+        impl_arith!($type, $other, $inner, Sub, sub, |this, other| this
+            - other);
+        impl_arith!($type, $other, $inner, Mul, mul, |this, other| this
+            * other);
+
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            AddAssign,
+            add_assign,
+            wrapping_add
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            MulAssign,
+            mul_assign,
+            wrapping_mul
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            DivAssign,
+            div_assign,
+            wrapping_div
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            RemAssign,
+            rem_assign,
+            wrapping_rem
+        );
+        impl_method_assign!(
+            $type,
+            $other,
+            $inner,
+            SubAssign,
+            sub_assign,
+            wrapping_sub
+        );
+
+        // Unlike the arithmetic operators, these methods return a new wrapped
+        // value rather than the raw result.
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            pub fn wrapping_add(self, rhs: $inner) -> Self {
+                strict_debug_assert!(
+                    self.0.checked_add(rhs),
+                    "wrapped",
+                    self.0,
+                    "+",
+                    rhs,
+                    ".."
+                );
+                let rhs = Self::residue(rhs);
+                Self::from_position(Self::add_mod(self.position(), rhs))
+            }
+            pub fn wrapping_sub(self, rhs: $inner) -> Self {
+                strict_debug_assert!(
+                    self.0.checked_sub(rhs),
+                    "wrapped",
+                    self.0,
+                    "-",
+                    rhs,
+                    ".."
+                );
+                let rhs = Self::residue(rhs);
+                Self::from_position(Self::sub_mod(self.position(), rhs))
+            }
+            pub fn wrapping_mul(self, rhs: $inner) -> Self {
+                strict_debug_assert!(
+                    self.0.checked_mul(rhs),
+                    "wrapped",
+                    self.0,
+                    "*",
+                    rhs,
+                    ".."
+                );
+                let lhs = Self::residue(self.0);
+                let rhs = Self::residue(rhs);
+                Self::from_residue(Self::mul_mod(lhs, rhs))
+            }
+            pub fn wrapping_div(self, rhs: $inner) -> Self {
+                let result = match self.0.checked_div(rhs) {
+                    Some(result) => Self::from(result),
+                    None if rhs == 0 => panic!("attempt to divide by zero"),
+                    // only $inner::MIN / -1 overflows, which is -$inner::MIN
+                    None => Self::from_residue(Self::sub_mod(
+                        0,
+                        Self::residue(self.0),
+                    )),
+                };
+                strict_debug_assert!(
+                    self.0.checked_div(rhs),
+                    "wrapped",
+                    self.0,
+                    "/",
+                    rhs,
+                    ".."
+                );
+                result
+            }
+            pub fn wrapping_rem(self, rhs: $inner) -> Self {
+                let result = self.0.wrapping_rem(rhs);
+                strict_debug_assert!(
+                    Some(result),
+                    "wrapped",
+                    self.0,
+                    "%",
+                    rhs,
+                    ".."
+                );
+                Self::from(result)
+            }
+
+            /// The value that, when added to `self`, wraps back around to
+            /// `MIN`. This is handy for undoing a rotation.
+            pub fn wrapping_complement(self) -> Self {
+                Self(MIN).overflowing_sub(self.0).0
+            }
+
+            /// Adds a value with different bounds, after first wrapping it
+            /// into this value's range. Note that `+=` with a differently
+            /// bounded value uses its raw inner value instead, which gives the
+            /// same result, but may count as wrapping where this doesn't.
+            pub fn add_rebound<
+                const OTHER_MIN: $inner,
+                const OTHER_MAX: $inner,
+            >(
+                self,
+                rhs: $other,
+            ) -> Self {
+                self.wrapping_add(rhs.rebound::<MIN, MAX>().inner())
+            }
+
+            /// Like [`Self::add_rebound`], but for subtraction.
+            pub fn sub_rebound<
+                const OTHER_MIN: $inner,
+                const OTHER_MAX: $inner,
+            >(
+                self,
+                rhs: $other,
+            ) -> Self {
+                self.wrapping_sub(rhs.rebound::<MIN, MAX>().inner())
+            }
+
+            /// Like [`Self::wrapping_add`], but also returns whether the
+            /// result had to be wrapped back into `MIN..MAX`.
+            pub fn overflowing_add(self, rhs: $inner) -> (Self, bool) {
+                let wrapped = match self.0.checked_add(rhs) {
+                    Some(result) => !Self::contains(result),
+                    None => true,
+                };
+                let rhs = Self::residue(rhs);
+                let position = Self::add_mod(self.position(), rhs);
+                (Self::from_position(position), wrapped)
+            }
+            /// Like [`Self::wrapping_sub`], but also returns whether the
+            /// result had to be wrapped back into `MIN..MAX`.
+            pub fn overflowing_sub(self, rhs: $inner) -> (Self, bool) {
+                let wrapped = match self.0.checked_sub(rhs) {
+                    Some(result) => !Self::contains(result),
+                    None => true,
+                };
+                let rhs = Self::residue(rhs);
+                let position = Self::sub_mod(self.position(), rhs);
+                (Self::from_position(position), wrapped)
+            }
+            /// Like [`Self::wrapping_mul`], but also returns whether the
+            /// result had to be wrapped back into `MIN..MAX`.
+            pub fn overflowing_mul(self, rhs: $inner) -> (Self, bool) {
+                let wrapped = match self.0.checked_mul(rhs) {
+                    Some(result) => !Self::contains(result),
+                    None => true,
+                };
+                let lhs = Self::residue(self.0);
+                let residue = Self::mul_mod(lhs, Self::residue(rhs));
+                (Self::from_residue(residue), wrapped)
+            }
+
+            /// Raises the value to the power of `exp`, wrapping modulo the
+            /// width of the range rather than that of the inner type.
+            pub fn pow(self, mut exp: u32) -> Self {
+                let mut base = Self::residue(self.0);
+                let mut result = Self::residue(1);
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        result = Self::mul_mod(result, base);
+                    }
+                    base = Self::mul_mod(base, base);
+                    exp >>= 1;
                 }
+                Self::from_residue(result)
             }
-            this - other
-        });
 
-        impl_arith_assign!($type, $other, $inner, AddAssign, add_assign, add);
-        impl_arith_assign!($type, $other, $inner, MulAssign, mul_assign, mul);
-        impl_arith_assign!($type, $other, $inner, DivAssign, div_assign, div);
-        impl_arith_assign!($type, $other, $inner, RemAssign, rem_assign, rem);
-        impl_arith_assign!($type, $other, $inner, SubAssign, sub_assign, sub);
+            /// Moves by a signed `delta`, wrapping in either direction. This
+            /// allows unsigned types to be moved backwards as well.
+            pub fn offset(self, delta: i64) -> Self {
+                let width = Self::width() as u128;
+                let rem = (delta.unsigned_abs() as u128 % width) as $uinner;
+                if delta < 0 {
+                    Self::from_position(Self::sub_mod(self.position(), rem))
+                } else {
+                    Self::from_position(Self::add_mod(self.position(), rem))
+                }
+            }
+        }
 
         impl_ord!($type, $other, $inner);
+        impl_hash!($type, $inner);
+        impl_bound_consts!($type, $inner);
+        impl_as_float!($type, $inner);
+        impl_default!($type, $inner);
+        impl_display!($type, $inner);
+        impl_deref!($type, $inner);
+        impl_into_inner!($type, $inner);
+        impl_min_max!($type, $inner);
+        impl_apply!($type, $inner, $inner, |value| Self::from(value).0);
+        impl_map!($type, $inner, map, Self, Self::from);
+        impl_clamped!($type, $inner, Self, Self::from);
+        impl_inner_mut!($type, $inner);
+        impl_rebound!($type, $other, $inner, $other, <$other>::from);
+        impl_midpoint!($type, $inner, Self::from);
+        impl_sum!($type, $inner);
+        impl_bitwise!($type, $other, $inner);
+
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// Returns an endless iterator that starts at `self`, and advances
+            /// by `step` (wrapping as usual) each time.
+            pub fn cycle(self, step: $inner) -> impl Iterator<Item = Self> {
+                iter::successors(Some(self), move |prev| {
+                    Some(prev.overflowing_add(step).0)
+                })
+            }
+
+            /// Returns an iterator from `start` up to (but not including)
+            /// `end`, advancing by `step` each time. If `end` is less than
+            /// `start`, the iterator wraps around past `MAX` to reach it.
+            /// Panics if `step` is zero.
+            pub fn step_by(start: Self, end: Self, step: usize) -> Steps<Self> {
+                let distance = Self::sub_mod(end.position(), start.position());
+                Steps::new(start, distance as u128, step, |start, distance| {
+                    let distance = distance as $uinner;
+                    let position = Self::add_mod(start.position(), distance);
+                    Self::from_position(position)
+                })
+            }
+
+            /// Interpolates towards `target` along the shorter way around the
+            /// range, where `t` is clamped to `0.0..=1.0`. If both ways are
+            /// the same length, the value moves upwards.
+            pub fn lerp_wrapped(self, target: Self, t: f64) -> Self {
+                let t = t.clamp(0.0, 1.0);
+                let up = Self::sub_mod(target.position(), self.position());
+                let down = Self::sub_mod(self.position(), target.position());
+
+                let scale = |distance: $uinner| {
+                    ((distance as f64 * t + 0.5) as $uinner).min(distance)
+                };
+
+                if up <= down {
+                    let step = scale(up);
+                    Self::from_position(Self::add_mod(self.position(), step))
+                } else {
+                    let step = scale(down);
+                    Self::from_position(Self::sub_mod(self.position(), step))
+                }
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl_num_bounded!($type, $inner, Self::from(MIN), Self::from(MAX - 1));
+
+        #[cfg(feature = "serde")]
+        impl_serde!($type, $inner, |inner| Ok(Self::from(inner)));
+    };
+}
+
+// Unlike the arithmetic operators, bitwise operators return a new wrapped
+// value, as their results are rarely meaningful outside of the range.
+macro_rules! impl_bitwise {
+    ($type:ty, $other:ty, $inner:ty) => {
+        impl_bitwise!(
+            $type, $other, $inner,
+            BitAnd, bitand, BitAndAssign, bitand_assign, &
+        );
+        impl_bitwise!(
+            $type, $other, $inner,
+            BitOr, bitor, BitOrAssign, bitor_assign, |
+        );
+        impl_bitwise!(
+            $type, $other, $inner,
+            BitXor, bitxor, BitXorAssign, bitxor_assign, ^
+        );
+        impl_shift!($type, $inner, Shl, shl, ShlAssign, shl_assign, <<);
+        impl_shift!($type, $inner, Shr, shr, ShrAssign, shr_assign, >>);
+
+        impl<const MIN: $inner, const MAX: $inner> Not for $type {
+            type Output = Self;
+            fn not(self) -> Self::Output { Self::from(!self.0) }
+        }
+    };
+    (
+        $type:ty, $other:ty, $inner:ty,
+        $trait:ident, $fn:ident, $assign:ident, $assign_fn:ident, $op:tt
+    ) => {
+        impl<const MIN: $inner, const MAX: $inner> $trait<$inner> for $type {
+            type Output = Self;
+            fn $fn(self, other: $inner) -> Self::Output {
+                Self::from(self.0 $op other)
+            }
+        }
+
+        impl<
+                const MIN: $inner,
+                const MAX: $inner,
+                const OTHER_MIN: $inner,
+                const OTHER_MAX: $inner,
+            > $trait<$other> for $type
+        {
+            type Output = Self;
+            fn $fn(self, other: $other) -> Self::Output {
+                Self::from(self.0 $op other.0)
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> $assign<$inner> for $type {
+            fn $assign_fn(&mut self, other: $inner) { *self = *self $op other }
+        }
+
+        impl<
+                const MIN: $inner,
+                const MAX: $inner,
+                const OTHER_MIN: $inner,
+                const OTHER_MAX: $inner,
+            > $assign<$other> for $type
+        {
+            fn $assign_fn(&mut self, other: $other) { *self = *self $op other }
+        }
+    };
+}
+
+// Shifts behave like the inner type's, so bits shifted past its width are
+// lost before the result is wrapped.
+macro_rules! impl_shift {
+    (
+        $type:ty, $inner:ty,
+        $trait:ident, $fn:ident, $assign:ident, $assign_fn:ident, $op:tt
+    ) => {
+        impl<const MIN: $inner, const MAX: $inner> $trait<u32> for $type {
+            type Output = Self;
+            fn $fn(self, rhs: u32) -> Self::Output {
+                Self::from(self.0 $op rhs)
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> $assign<u32> for $type {
+            fn $assign_fn(&mut self, rhs: u32) { *self = *self $op rhs }
+        }
+    };
+}
+
+// Implementations that only make sense for signed integers.
+macro_rules! impl_signed {
+    ($type:ty, $inner:ty) => {
+        impl<const MIN: $inner, const MAX: $inner> Neg for $type {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                match self.0.checked_neg() {
+                    Some(inner) => Self::from(inner),
+                    // -$inner::MIN is one greater than $inner::MAX
+                    None => Self::from(<$inner>::MAX).overflowing_add(1).0,
+                }
+            }
+        }
+
+        impl<const MIN: $inner, const MAX: $inner> $type {
+            /// The absolute value, wrapped back into range. As with negation,
+            /// the most negative inner value doesn't cause an overflow.
+            pub fn abs(self) -> Self {
+                if self.0 < 0 {
+                    -self
+                } else {
+                    self
+                }
+            }
+
+            /// The Euclidean remainder of dividing by `m`, which unlike `%` is
+            /// never negative, wrapped back into range.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `m` is zero.
+            pub fn rem_euclid_wrapped(self, m: $inner) -> Self {
+                // only $inner::MIN % -1 overflows, and its remainder is 0
+                Self::from(self.0.wrapping_rem_euclid(m))
+            }
+        }
     };
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingU8<const MIN: u8, const MAX: u8>(u8);
-impl_all!(WrappingU8<MIN, MAX>, WrappingU8<OTHER_MIN, OTHER_MAX>, u8);
+impl_all!(WrappingU8<MIN, MAX>, WrappingU8<OTHER_MIN, OTHER_MAX>, u8, u8);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingU16<const MIN: u16, const MAX: u16>(u16);
-impl_all!(WrappingU16<MIN, MAX>, WrappingU16<OTHER_MIN, OTHER_MAX>, u16);
+impl_all!(WrappingU16<MIN, MAX>, WrappingU16<OTHER_MIN, OTHER_MAX>, u16, u16);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingU32<const MIN: u32, const MAX: u32>(u32);
-impl_all!(WrappingU32<MIN, MAX>, WrappingU32<OTHER_MIN, OTHER_MAX>, u32);
+impl_all!(WrappingU32<MIN, MAX>, WrappingU32<OTHER_MIN, OTHER_MAX>, u32, u32);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingU64<const MIN: u64, const MAX: u64>(u64);
-impl_all!(WrappingU64<MIN, MAX>, WrappingU64<OTHER_MIN, OTHER_MAX>, u64);
+impl_all!(WrappingU64<MIN, MAX>, WrappingU64<OTHER_MIN, OTHER_MAX>, u64, u64);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingU128<const MIN: u128, const MAX: u128>(u128);
-impl_all!(WrappingU128<MIN, MAX>, WrappingU128<OTHER_MIN, OTHER_MAX>, u128);
+impl_all!(WrappingU128<MIN, MAX>, WrappingU128<OTHER_MIN, OTHER_MAX>, u128, u128);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingUSize<const MIN: usize, const MAX: usize>(usize);
-impl_all!(WrappingUSize<MIN, MAX>, WrappingUSize<OTHER_MIN, OTHER_MAX>, usize);
+impl_all!(WrappingUSize<MIN, MAX>, WrappingUSize<OTHER_MIN, OTHER_MAX>, usize, usize);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingI8<const MIN: i8, const MAX: i8>(i8);
-impl_all!(WrappingI8<MIN, MAX>, WrappingI8<OTHER_MIN, OTHER_MAX>, i8);
+impl_all!(WrappingI8<MIN, MAX>, WrappingI8<OTHER_MIN, OTHER_MAX>, i8, u8);
+impl_signed!(WrappingI8<MIN, MAX>, i8);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingI16<const MIN: i16, const MAX: i16>(i16);
-impl_all!(WrappingI16<MIN, MAX>, WrappingI16<OTHER_MIN, OTHER_MAX>, i16);
+impl_all!(WrappingI16<MIN, MAX>, WrappingI16<OTHER_MIN, OTHER_MAX>, i16, u16);
+impl_signed!(WrappingI16<MIN, MAX>, i16);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingI32<const MIN: i32, const MAX: i32>(i32);
-impl_all!(WrappingI32<MIN, MAX>, WrappingI32<OTHER_MIN, OTHER_MAX>, i32);
+impl_all!(WrappingI32<MIN, MAX>, WrappingI32<OTHER_MIN, OTHER_MAX>, i32, u32);
+impl_signed!(WrappingI32<MIN, MAX>, i32);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingI64<const MIN: i64, const MAX: i64>(i64);
-impl_all!(WrappingI64<MIN, MAX>, WrappingI64<OTHER_MIN, OTHER_MAX>, i64);
+impl_all!(WrappingI64<MIN, MAX>, WrappingI64<OTHER_MIN, OTHER_MAX>, i64, u64);
+impl_signed!(WrappingI64<MIN, MAX>, i64);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingI128<const MIN: i128, const MAX: i128>(i128);
-impl_all!(WrappingI128<MIN, MAX>, WrappingI128<OTHER_MIN, OTHER_MAX>, i128);
+impl_all!(WrappingI128<MIN, MAX>, WrappingI128<OTHER_MIN, OTHER_MAX>, i128, u128);
+impl_signed!(WrappingI128<MIN, MAX>, i128);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WrappingISize<const MIN: isize, const MAX: isize>(isize);
-impl_all!(WrappingISize<MIN, MAX>, WrappingISize<OTHER_MIN, OTHER_MAX>, isize);
+impl_all!(WrappingISize<MIN, MAX>, WrappingISize<OTHER_MIN, OTHER_MAX>, isize, usize);
+impl_signed!(WrappingISize<MIN, MAX>, isize);
 
 #[cfg(test)]
 mod tests {
@@ -150,6 +687,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn overflow_will_wrap() {
         let mut a = WrappingU32::<0, 10>(4);
         assert_eq!(a + 8, 12);
@@ -166,6 +704,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn bounded_underflow_will_wrap() {
         let mut a = WrappingU32::<4, 8>(6);
         assert_eq!(a - 3, 3);
@@ -179,6 +718,28 @@ mod tests {
     }
 
     #[test]
+    fn below_nonzero_min_will_wrap() {
+        assert_eq!(WrappingU32::<5, 9>::from(3), 7);
+        assert_eq!(WrappingU32::<5, 9>::from(4), 8);
+        assert_eq!(WrappingU32::<5, 9>::from(1), 5);
+        assert_eq!(WrappingU32::<5, 9>::from(0), 8);
+
+        fn reference(inner: u32, min: u32, max: u32) -> u32 {
+            let (inner, min, max) = (inner as i64, min as i64, max as i64);
+            (min + (inner - min).rem_euclid(max - min)) as u32
+        }
+
+        for inner in 0..100 {
+            let expected = |min, max| reference(inner, min, max);
+            assert_eq!(WrappingU32::<5, 9>::from(inner), expected(5, 9));
+            assert_eq!(WrappingU32::<7, 10>::from(inner), expected(7, 10));
+            assert_eq!(WrappingU32::<50, 64>::from(inner), expected(50, 64));
+            assert_eq!(WrappingU32::<99, 100>::from(inner), expected(99, 100));
+        }
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn real_underflow_will_wrap() {
         let mut a = WrappingU32::<0, 4>(2);
         let mut b = WrappingU32::<0, 4>(0);
@@ -189,6 +750,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn addassign_matches_new() {
         let mut a = WrappingU32::<0, 10>(4);
 
@@ -233,6 +795,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn signed_integers_subtract_normally() {
         let mut foo = WrappingI32::<-10, 10>(5);
         foo -= 7;
@@ -242,6 +805,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn range_is_not_inclusive() {
         let mut a = WrappingUSize::<0, 10>::from(5);
         a += 5;
@@ -273,6 +837,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
     fn range_is_not_inclusive_signed() {
         let mut a = WrappingISize::<-10, 10>::from(5);
         a += 5;
@@ -280,11 +845,779 @@ mod tests {
         assert_eq!(a, -10);
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_only_writes_inner() {
+        let a = WrappingU32::<2, 8>::from(5);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, "5");
+
+        let b: WrappingU32<2, 8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize_will_wrap() {
+        let a: WrappingU32<2, 8> = serde_json::from_str("10").unwrap();
+        assert_eq!(a, 4);
+
+        let b: WrappingI32<-10, 10> = serde_json::from_str("12").unwrap();
+        assert_eq!(b, -8);
+    }
+
+    #[test]
+    fn hash_matches_eq() {
+        use crate::test_utils::hash_of;
+
+        let a = WrappingU8::<0, 16>::new(5);
+        let b = WrappingU8::<2, 10>::new(5);
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn default_is_min() {
+        assert_eq!(WrappingU32::<2, 8>::default(), 2);
+        assert_eq!(WrappingU8::<0, 10>::default(), 0);
+        assert_eq!(WrappingI32::<-10, 10>::default(), -10);
+        assert_eq!(WrappingI64::<3, 5>::default(), 3);
+    }
+
+    #[test]
+    fn display_shows_inner() {
+        let a = WrappingI32::<-10, 10>::new(-5);
+        assert_eq!(format!("{}", a), "-5");
+        assert_eq!(format!("{:>4}", a), "  -5");
+        assert_eq!(format!("{:?}", a), "WrappingI32(-5)");
+    }
+
+    #[test]
+    fn parse_will_wrap() {
+        assert_eq!("5".parse::<WrappingU32<2, 8>>().unwrap(), 5);
+        assert_eq!("10".parse::<WrappingU32<2, 8>>().unwrap(), 4);
+        assert_eq!("12".parse::<WrappingI32<-10, 10>>().unwrap(), -8);
+        assert!("abc".parse::<WrappingU32<2, 8>>().is_err());
+        assert!("-1".parse::<WrappingU32<2, 8>>().is_err());
+    }
+
+    #[test]
+    fn neg_will_wrap() {
+        let a = WrappingI32::<-100, 50>::new(40);
+        assert_eq!(-a, -40);
+
+        // -80 (bounds: -100..50) = -70
+        let b = WrappingI32::<-100, 50>::new(-80);
+        assert_eq!(-b, -70);
+
+        // -(-100) (bounds: -100..100) = -100
+        let c = WrappingI32::<-100, 100>::new(-100);
+        assert_eq!(-c, -100);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn wrapping_methods_return_self() {
+        let a = WrappingU32::<2, 8>::from(5);
+        let b: WrappingU32<2, 8> = a.wrapping_add(5);
+        assert_eq!(b, 4);
+
+        // chained operations stay within bounds
+        let c = a.wrapping_add(5).wrapping_mul(3).wrapping_sub(4);
+        assert_eq!(c, 2);
+        assert_eq!(a.wrapping_div(2), 2);
+        assert_eq!(a.wrapping_rem(3), 2);
+    }
+
+    #[test]
+    fn overflowing_methods() {
+        let a = WrappingU32::<2, 8>::from(5);
+        assert_eq!(a.overflowing_add(2), (WrappingU32::from(7), false));
+        assert_eq!(a.overflowing_add(3), (WrappingU32::from(2), true));
+        assert_eq!(a.overflowing_sub(3), (WrappingU32::from(2), false));
+        assert_eq!(a.overflowing_sub(4), (WrappingU32::from(7), true));
+        assert_eq!(a.overflowing_sub(100), (WrappingU32::from(7), true));
+        assert_eq!(a.overflowing_mul(1), (WrappingU32::from(5), false));
+        assert_eq!(a.overflowing_mul(2), (WrappingU32::from(4), true));
+
+        // overflowing the inner type always counts as wrapping
+        let b = WrappingI8::<-100, 100>::from(90);
+        assert_eq!(b.overflowing_add(-10), (WrappingI8::from(80), false));
+        assert_eq!(b.overflowing_add(100), (WrappingI8::from(-10), true));
+        assert_eq!(b.overflowing_mul(-2), (WrappingI8::from(20), true));
+
+        // a full lap still wraps, even though the value is unchanged
+        assert_eq!(a.overflowing_add(6), (a, true));
+    }
+
+    #[test]
+    #[cfg(all(feature = "strict-debug", debug_assertions))]
+    #[should_panic(expected = "7 + 2 was wrapped to fit in 0..8")]
+    fn strict_debug_panics_on_wrap() {
+        let _ = WrappingU32::<0, 8>::from(7).wrapping_add(2);
+    }
+
+    #[test]
+    #[cfg(all(feature = "strict-debug", debug_assertions))]
+    #[should_panic(expected = "3 - 8 was wrapped to fit in -4..4")]
+    fn strict_debug_panics_on_assign_wrap() {
+        let mut a = WrappingI32::<-4, 4>::from(3);
+        a -= 8;
+    }
+
+    #[test]
+    #[cfg(feature = "strict-debug")]
+    fn strict_debug_allows_in_range_ops() {
+        let mut a = WrappingI32::<-4, 4>::from(1);
+        assert_eq!(a.wrapping_add(2), 3);
+        assert_eq!(a.wrapping_sub(5), -4);
+        assert_eq!(a.wrapping_mul(-3), -3);
+        a += 2;
+        assert_eq!(a, 3);
+
+        // explicitly asking for the wrap is fine, as is constructing from an
+        // out-of-range value
+        assert_eq!(a.overflowing_add(1), (WrappingI32::from(-4), true));
+        assert_eq!(WrappingI32::<-4, 4>::from(4), -4);
+        assert_eq!(a + 10, 13);
+
+        let mut cursor = crate::wrapping::Cursor::<0, 3>::new(2);
+        assert_eq!(cursor.next(), 0);
+        assert_eq!(cursor.prev(), 2);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn wrapping_complement() {
+        for x in WrappingU8::<0, 5>::all() {
+            let complement = x.wrapping_complement();
+            assert_eq!(x.wrapping_add(complement.inner()), 0);
+            assert_eq!(WrappingU8::<0, 5>::from(x + complement), 0);
+        }
+        assert_eq!(WrappingU8::<0, 5>::from(0).wrapping_complement(), 0);
+        assert_eq!(WrappingU8::<0, 5>::from(1).wrapping_complement(), 4);
+
+        // MIN isn't always zero
+        for x in WrappingI8::<-3, 4>::all() {
+            let complement = x.wrapping_complement();
+            assert_eq!(x.wrapping_add(complement.inner()), -3);
+        }
+        for x in WrappingU8::<2, 8>::all() {
+            let complement = x.wrapping_complement();
+            assert_eq!(x.wrapping_add(complement.inner()), 2);
+        }
+
+        // undoing a rotation
+        let start = WrappingU8::<0, 12>::from(7);
+        let step = WrappingU8::<0, 12>::from(5);
+        let rotated = start.wrapping_add(step.inner());
+        let complement = step.wrapping_complement().inner();
+        assert_eq!(rotated.wrapping_add(complement), start);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn large_subtraction_will_not_overflow() {
+        // 245 - 255 (bounds: 200..250) = 240
+        let mut a = WrappingU8::<200, 250>::new(245);
+        assert_eq!(a.wrapping_sub(255), 240);
+        a -= 255;
+        assert_eq!(a, 240);
+
+        // 201 - 251 (bounds: 200..250) = 200
+        let mut b = WrappingU8::<200, 250>::new(201);
+        b -= 251;
+        assert_eq!(b, 200);
+        b -= u8::MAX;
+        assert_eq!(b, 245);
+
+        const BIG: u32 = 4_000_000_000;
+        let c = WrappingU32::<BIG, { BIG + 10 }>::new(BIG + 1);
+        assert_eq!(c.wrapping_sub(u32::MAX), BIG + 6);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn subtraction_matches_reference() {
+        // reference implementation using wider arithmetic
+        fn reference(inner: u8, other: u8, min: u8, max: u8) -> u8 {
+            let width = (max - min) as i32;
+            let offset = inner as i32 - other as i32 - min as i32;
+            (min as i32 + offset.rem_euclid(width)) as u8
+        }
+
+        for inner in 200..250 {
+            for other in [0, 1, 49, 50, 51, 199, 200, 249, 250, 254, 255] {
+                let a = WrappingU8::<200, 250>::new(inner);
+                let expected = reference(inner, other, 200, 250);
+                assert_eq!(a.wrapping_sub(other), expected);
+            }
+        }
+        for inner in 5..9 {
+            for other in 0..=u8::MAX {
+                let a = WrappingU8::<5, 9>::new(inner);
+                let expected = reference(inner, other, 5, 9);
+                assert_eq!(a.wrapping_sub(other), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn signed_wrapping_matches_reference() {
+        fn reference(inner: i8, min: i8, max: i8) -> i8 {
+            let width = max as i16 - min as i16;
+            (min as i16 + (inner as i16 - min as i16).rem_euclid(width)) as i8
+        }
+
+        for inner in i8::MIN..=i8::MAX {
+            let expected = |min, max| reference(inner, min, max);
+            assert_eq!(WrappingI8::<-10, 10>::new(inner), expected(-10, 10));
+            assert_eq!(WrappingI8::<-3, 17>::new(inner), expected(-3, 17));
+            assert_eq!(WrappingI8::<-20, -7>::new(inner), expected(-20, -7));
+            assert_eq!(WrappingI8::<-128, 1>::new(inner), expected(-128, 1));
+            assert_eq!(WrappingI8::<-1, 127>::new(inner), expected(-1, 127));
+        }
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn full_range_bounds_will_wrap() {
+        let mut a = WrappingI8::<-128, 127>::new(127);
+        assert_eq!(a, -128);
+        assert_eq!(a.range(), -128..127);
+
+        a += 100;
+        assert_eq!(a, -28);
+        a += 100;
+        assert_eq!(a, 72);
+        a += 100;
+        assert_eq!(a, -83);
+        a -= 100;
+        assert_eq!(a, 72);
+        a *= 4;
+        assert_eq!(a, 33);
+        assert_eq!(-WrappingI8::<-128, 127>::new(-128), -127);
+
+        // 128 is out of range, so MIN / -1 wraps just like negating MIN
+        let mut c = WrappingI8::<-128, 127>::new(-128);
+        assert_eq!(c.wrapping_div(-1), -127);
+        assert_eq!(c.wrapping_rem(-1), 0);
+        assert_eq!(WrappingI8::<-128, 0>::new(-128).wrapping_div(-1), -128);
+        c /= -1;
+        assert_eq!(c, -127);
+        c = WrappingI8::<-128, 127>::new(-128);
+        c %= -1;
+        assert_eq!(c, 0);
+
+        let mut b = WrappingU8::<0, 255>::new(255);
+        assert_eq!(b, 0);
+        b -= 1;
+        assert_eq!(b, 254);
+        b += 200;
+        assert_eq!(b, 199);
+        b *= 3;
+        assert_eq!(b, 87);
+        b -= u8::MAX;
+        assert_eq!(b, 87);
+    }
+
+    #[test]
+    fn width_and_cardinality() {
+        assert_eq!(WrappingU8::<0, 255>::width(), 255);
+        assert_eq!(WrappingU8::<0, 255>::cardinality(), 255);
+        assert_eq!(WrappingI8::<-128, 127>::width(), 255);
+        assert_eq!(WrappingU32::<2, 8>::cardinality(), 6);
+
+        type Full = WrappingU128<0, { u128::MAX }>;
+        assert_eq!(Full::width(), u128::MAX);
+        assert_eq!(Full::cardinality(), u128::MAX);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn large_arithmetic_will_not_overflow() {
+        let a = WrappingU8::<200, 250>::new(240);
+        assert_eq!(a.wrapping_add(20), 210);
+        assert_eq!(a.wrapping_mul(2), 230);
+        assert_eq!(a.wrapping_mul(u8::MAX), 200);
+
+        type FullI128 = WrappingI128<{ i128::MIN }, { i128::MAX }>;
+        let b = FullI128::new(i128::MAX - 1);
+        assert_eq!(b.wrapping_add(1), i128::MIN);
+        assert_eq!(b.wrapping_mul(2), -3);
+    }
+
+    #[test]
+    fn rem_euclid_wrapped() {
+        let a = WrappingI32::<-10, 10>::from(-7);
+        assert_eq!(a % 3, -1);
+        assert_eq!(a.rem_euclid_wrapped(3), 2);
+        assert_eq!(a.rem_euclid_wrapped(-3), 2);
+        assert_eq!(a.rem_euclid_wrapped(7), 0);
+        assert_eq!(WrappingI32::<-10, 10>::from(7).rem_euclid_wrapped(4), 3);
+
+        // the remainder itself is wrapped if it's out of range
+        let b = WrappingI8::<-4, 4>::from(-1);
+        assert_eq!(b.rem_euclid_wrapped(8), -1);
+        assert_eq!(b.rem_euclid_wrapped(6), -3);
+
+        type Full = WrappingI8<{ i8::MIN }, { i8::MAX }>;
+        assert_eq!(Full::from(-128).rem_euclid_wrapped(-1), 0);
+        assert_eq!(Full::from(-128).rem_euclid_wrapped(100), 72);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn wrapping_div_by_zero_panics() {
+        let _ = WrappingI8::<-128, 127>::new(-128).wrapping_div(0);
+    }
+
     #[test]
     #[should_panic]
-    fn cannot_use_equal_bounds() { let _ = WrappingUSize::<10, 10>::from(5); }
+    fn rem_euclid_wrapped_by_zero_panics() {
+        let _ = WrappingI32::<-10, 10>::from(-7).rem_euclid_wrapped(0);
+    }
+
+    #[test]
+    fn neg_of_inner_min_will_wrap() {
+        // -(-128) (bounds: -128..100) = -100
+        let a = WrappingI8::<-128, 100>::new(-128);
+        assert_eq!(-a, -100);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn is_at_min() {
+        let mut a = WrappingU32::<2, 8>::clamp(7);
+        assert!(!a.is_at_min());
+        a += 1;
+        assert!(a.is_at_min());
+    }
+
+    #[test]
+    fn contains() {
+        assert!(WrappingI32::<-2, 8>::contains(-2));
+        assert!(WrappingI32::<-2, 8>::contains(7));
+        assert!(!WrappingI32::<-2, 8>::contains(8));
+        assert!(!WrappingI32::<-2, 8>::contains(-3));
+    }
+
+    #[test]
+    fn offset() {
+        let a = WrappingU8::<0, 16>::from(0);
+        assert_eq!(a.offset(-1), 15);
+        assert_eq!(a.offset(1), 1);
+        assert_eq!(a.offset(0), 0);
+        assert_eq!(a.offset(16 * 3 + 5), 5);
+        assert_eq!(a.offset(-(16 * 3 + 5)), 11);
+        assert_eq!(a.offset(i64::MAX), (i64::MAX % 16) as u8);
+        assert_eq!(a.offset(i64::MIN), 0);
+
+        let b = WrappingI32::<-5, 5>::from(4);
+        assert_eq!(b.offset(1), -5);
+        assert_eq!(b.offset(-1000), 4);
+        assert_eq!(b.offset(1003), -3);
+
+        let c = WrappingU128::<0, { u128::MAX }>::from(0);
+        assert_eq!(c.offset(-1), u128::MAX - 1);
+        assert_eq!(c.offset(i64::MIN), u128::MAX - (1 << 63));
+    }
+
+    #[test]
+    fn inner_mut() {
+        let mut a = WrappingU8::<0, 16>::from(10);
+        {
+            let mut inner = a.inner_mut();
+            *inner *= 3;
+            assert_eq!(*inner, 30);
+            *inner += 4;
+        }
+        assert_eq!(a, 2);
+
+        *a.inner_mut() = 0;
+        assert_eq!(a, 0);
+    }
+
+    #[test]
+    fn set() {
+        let mut a = WrappingU8::<0, 16>::from(10);
+        a.set(3);
+        assert_eq!(a, 3);
+        a.set(20);
+        assert_eq!(a, 4);
+    }
+
+    #[test]
+    fn deref_to_inner() {
+        fn takes_ref(value: &u32) -> u32 { *value * 2 }
+
+        let a = WrappingU32::<0, 10>::from(13);
+        assert_eq!(takes_ref(&a), 6);
+        assert_eq!(*a, 3);
+        assert_eq!(a.as_ref(), &3);
+        assert_eq!(a.count_ones(), 2);
+    }
+
+    #[test]
+    fn into_inner() {
+        let a = WrappingU32::<2, 8>::from(10);
+        assert_eq!(a.into_inner(), 4);
+        let b: u32 = a.into();
+        assert_eq!(b, 4);
+    }
+
+    #[test]
+    fn std_wrapping_round_trip() {
+        use core::num::Wrapping as StdWrapping;
+
+        type Full = WrappingU32<0, { u32::MAX }>;
+        for value in [0, 1, 12345, u32::MAX / 2, u32::MAX - 1] {
+            let ours = Full::from(StdWrapping(value));
+            assert_eq!(ours, value);
+            assert_eq!(StdWrapping::from(ours), StdWrapping(value));
+        }
+        assert_eq!(Full::from(StdWrapping(u32::MAX)), 0);
+
+        type Signed = WrappingI8<{ i8::MIN }, { i8::MAX }>;
+        for value in i8::MIN..i8::MAX {
+            let ours = Signed::from(StdWrapping(value));
+            assert_eq!(StdWrapping::from(ours), StdWrapping(value));
+        }
+
+        // smaller bounds wrap as usual
+        assert_eq!(WrappingU32::<2, 8>::from(StdWrapping(10)), 4);
+    }
+
+    #[test]
+    fn map() {
+        let a = WrappingU32::<2, 8>::from(6);
+        assert_eq!(a.map(|x| x * 2), 6);
+        assert_eq!(a.map(|x| x - 1), 5);
+        assert_eq!(WrappingI8::<-4, 4>::from(3).map(|x| -x * 2), 2);
+    }
+
+    #[test]
+    fn apply() {
+        assert_eq!(WrappingU32::<2, 8>::apply(10), 4);
+        assert_eq!(WrappingU32::<2, 8>::apply(5), 5);
+        assert_eq!(WrappingI8::<-10, 10>::apply(-15), 5);
+    }
+
+    #[test]
+    fn min_max_with() {
+        let a = WrappingI8::<-5, 5>::from(4);
+        let b = WrappingI8::<-5, 5>::from(6);
+        assert_eq!(a.max_with(b), 4);
+        assert_eq!(a.min_with(b), -4);
+    }
+
+    #[test]
+    fn rebound() {
+        let a = WrappingU32::<0, 1000>::from(500);
+        assert_eq!(a.rebound::<0, 100>(), 0);
+        assert_eq!(a.rebound::<0, 300>(), 200);
+        assert_eq!(a.rebound::<600, 700>(), 600);
+        assert_eq!(a.rebound::<400, 2000>(), 500);
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(WrappingI32::<-5, 5>::from(-3).abs(), 3);
+        assert_eq!(WrappingI32::<-5, 5>::from(3).abs(), 3);
+        assert_eq!(WrappingI32::<-5, 5>::from(-5).abs(), -5);
+        assert_eq!(WrappingI8::<-128, 127>::from(-128).abs(), -127);
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(WrappingU32::<0, 100>::from(3).pow(4), 81);
+        assert_eq!(WrappingU32::<0, 7>::from(3).pow(100), 4);
+        assert_eq!(WrappingU32::<5, 100>::from(10).pow(0), 96);
+        assert_eq!(WrappingI32::<-5, 5>::from(3).pow(3), -3);
+
+        // 2^(p - 1) = 1 (mod p) for prime p
+        let a = WrappingU64::<0, 1_000_000_007>::from(2);
+        assert_eq!(a.pow(1_000_000_006), 1);
+        let b = WrappingU8::<0, 251>::from(200);
+        assert_eq!(b.pow(250), 1);
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(WrappingU8::<0, 255>::midpoint(), 127);
+        assert_eq!(WrappingI32::<-5, 5>::midpoint(), 0);
+        assert_eq!(WrappingU32::<3, 4>::midpoint(), 3);
+    }
+
+    #[test]
+    fn cycle() {
+        let a = WrappingU8::<0, 4>::from(0);
+        let first: Vec<_> = a.cycle(1).take(10).collect();
+        assert_eq!(first, [0, 1, 2, 3, 0, 1, 2, 3, 0, 1]);
+
+        let b = WrappingI8::<-3, 3>::from(2);
+        let first: Vec<_> = b.cycle(-2).take(5).collect();
+        assert_eq!(first, [2, 0, -2, 2, 0]);
+
+        // stepping by more than the width never overflows
+        let c = WrappingU8::<250, 255>::from(250);
+        let first: Vec<_> = c.cycle(254).take(5).collect();
+        assert_eq!(first, [250, 254, 253, 252, 251]);
+        assert_eq!(c.cycle(7).nth(1_000_000).unwrap(), 250);
+    }
+
+    #[test]
+    fn test_all() {
+        let all: Vec<_> = WrappingU32::<3, 7>::all().rev().collect();
+        assert_eq!(all, [6, 5, 4, 3]);
+        assert_eq!(WrappingU32::<3, 7>::all().len(), 4);
+        assert_eq!(WrappingU8::<0, 255>::all().len(), 255);
+    }
+
+    #[test]
+    fn test_step_by() {
+        type Foo = WrappingU8<0, 8>;
+        let steps: Vec<_> = Foo::step_by(Foo::from(0), Foo::from(5), 1)
+            .collect();
+        assert_eq!(steps, [0, 1, 2, 3, 4]);
+
+        let steps: Vec<_> = Foo::step_by(Foo::from(1), Foo::from(7), 2)
+            .collect();
+        assert_eq!(steps, [1, 3, 5]);
+
+        // naively stepping from 5 would go past MAX
+        let steps: Vec<_> = Foo::step_by(Foo::from(5), Foo::from(2), 1)
+            .collect();
+        assert_eq!(steps, [5, 6, 7, 0, 1]);
+        let steps: Vec<_> = Foo::step_by(Foo::from(6), Foo::from(5), 3)
+            .collect();
+        assert_eq!(steps, [6, 1, 4]);
+
+        assert_eq!(Foo::step_by(Foo::from(3), Foo::from(3), 1).count(), 0);
+    }
+
+    #[test]
+    fn step_by_full_range() {
+        type Foo = WrappingU8<0, 255>;
+        let steps: Vec<_> =
+            Foo::step_by(Foo::from(250), Foo::from(2), 3).collect();
+        assert_eq!(steps, [250, 253, 1]);
+
+        type Bar = WrappingI8<-128, 127>;
+        let count = Bar::step_by(Bar::from(0), Bar::from(-1), 1).count();
+        assert_eq!(count, 254);
+    }
 
     #[test]
     #[should_panic]
-    fn cannot_use_invalid_bounds() { let _ = WrappingUSize::<15, 10>::from(5); }
+    fn cannot_step_by_zero() {
+        let a = WrappingU8::<0, 8>::from(0);
+        let _ = WrappingU8::step_by(a, a, 0);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn test_sum() {
+        let values = [400, 300, 500, 100];
+        let sum: WrappingU32<0, 1000> = values.iter().copied().sum();
+        assert_eq!(sum, 300);
+
+        let values = [WrappingU8::<0, 200>::from(150); 3];
+        let sum: WrappingU8<0, 200> = values.into_iter().sum();
+        assert_eq!(sum, 50);
+
+        let sum: WrappingI8<-10, 10> = [-8, -8, -8].into_iter().sum();
+        assert_eq!(sum, -4);
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn test_product() {
+        let product: WrappingU8<0, 200> = [10, 10, 10].into_iter().product();
+        assert_eq!(product, 0);
+        let product: WrappingU8<0, 7> = [3, 4, 5].into_iter().product();
+        assert_eq!(product, 4);
+    }
+
+    #[test]
+    fn test_bitand() {
+        let a = WrappingU8::<0, 200>::from(0b1100);
+        assert_eq!(a & 0b1010, 0b1000);
+        assert_eq!(a & WrappingU8::<0, 100>::from(0b0110), 0b0100);
+
+        // results below MIN are wrapped
+        let mut b = WrappingU8::<4, 16>::from(0b1100);
+        b &= 0b0011;
+        assert_eq!(b, 12);
+    }
+
+    #[test]
+    fn test_bitor() {
+        let a = WrappingU8::<0, 200>::from(0b1100);
+        assert_eq!(a | 0b0011, 0b1111);
+
+        // 192 | 15 = 207, which wraps to 7
+        let mut b = WrappingU8::<0, 200>::from(192);
+        b |= 15;
+        assert_eq!(b, 7);
+    }
+
+    #[test]
+    fn test_bitxor() {
+        let a = WrappingU8::<0, 200>::from(0b1100);
+        assert_eq!(a ^ 0b1010, 0b0110);
+
+        // 128 ^ 127 = 255, which wraps to 55
+        let mut b = WrappingU8::<0, 200>::from(128);
+        b ^= WrappingU8::<0, 128>::from(127);
+        assert_eq!(b, 55);
+    }
+
+    #[test]
+    fn test_not() {
+        // !0 = 255, which wraps to 5
+        let a = WrappingU8::<0, 250>::from(0);
+        assert_eq!(!a, 5);
+        assert_eq!(!WrappingU8::<0, 255>::from(15), 240);
+
+        let b = WrappingI8::<-10, 10>::from(3);
+        assert_eq!(!b, -4);
+    }
+
+    #[test]
+    fn test_shifts() {
+        let a = WrappingU8::<0, 200>::from(3);
+        assert_eq!(a << 2, 12);
+        assert_eq!(a >> 1, 1);
+
+        // 3 << 6 = 192, 3 << 7 = 384 (which is 128 as a u8)
+        let mut b = WrappingU8::<0, 100>::from(3);
+        b <<= 6;
+        assert_eq!(b, 92);
+        assert_eq!(WrappingU8::<0, 100>::from(3) << 7, 28);
+
+        let mut c = WrappingU32::<16, 64>::from(32);
+        c >>= 2;
+        assert_eq!(c, 56);
+    }
+
+    #[test]
+    fn max_boundary_wraps_to_min() {
+        assert_eq!(WrappingU32::<3, 9>::from(8), 8);
+        assert_eq!(WrappingU32::<3, 9>::from(9), 3);
+        assert_eq!(WrappingU32::<3, 9>::from(10), 4);
+        assert_eq!(WrappingI32::<-5, 5>::from(5), -5);
+        assert_eq!(WrappingI32::<-5, 5>::from(6), -4);
+        assert_eq!(WrappingI32::<-5, 5>::from(-6), 4);
+
+        // MIN - 1 wraps to MAX - 1
+        assert_eq!(WrappingU32::<3, 9>::from(2), 8);
+        assert_eq!(WrappingU8::<1, 255>::from(255), 1);
+        assert_eq!(WrappingU8::<1, 255>::from(0), 254);
+        assert_eq!(WrappingI8::<-128, 127>::from(127), -128);
+        assert_eq!(WrappingI64::<-10, 0>::from(0), -10);
+        assert_eq!(WrappingI64::<-10, 0>::from(1), -9);
+        assert_eq!(WrappingI64::<-10, 0>::from(-11), -1);
+    }
+
+    // Exact multiples of the width below MIN once wrapped to MAX, which is out
+    // of range. Run with debug-invariants to check every assign operator too.
+    #[test]
+    fn below_min_always_lands_in_range() {
+        type Foo = WrappingI8<-3, 5>;
+        for inner in i8::MIN..=i8::MAX {
+            let expected = (inner as i16 + 3).rem_euclid(8) as i8 - 3;
+            assert_eq!(Foo::from(inner), expected, "from({})", inner);
+            assert!(Foo::contains(Foo::from(inner).inner()));
+        }
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn below_min_assign_lands_in_range() {
+        let mut a = WrappingU8::<10, 20>::from(15);
+        a -= 5;
+        assert_eq!(a, 10);
+        a -= 10;
+        assert_eq!(a, 10);
+        a -= 20;
+        assert_eq!(a, 10);
+    }
+
+    #[test]
+    fn lerp_wrapped() {
+        type Degrees = WrappingU32<0, 360>;
+        let a = Degrees::from(350);
+        let b = Degrees::from(10);
+
+        // the short way round is forwards, across the boundary
+        assert_eq!(a.lerp_wrapped(b, 0.0), 350);
+        assert_eq!(a.lerp_wrapped(b, 0.25), 355);
+        assert_eq!(a.lerp_wrapped(b, 0.5), 0);
+        assert_eq!(a.lerp_wrapped(b, 1.0), 10);
+        assert_eq!(b.lerp_wrapped(a, 0.5), 0);
+        assert_eq!(b.lerp_wrapped(a, 0.75), 355);
+
+        // t is clamped, so the target is never overshot
+        assert_eq!(a.lerp_wrapped(b, 2.0), 10);
+        assert_eq!(a.lerp_wrapped(b, -1.0), 350);
+
+        // equidistant targets always move upwards
+        let c = Degrees::from(90);
+        assert_eq!(c.lerp_wrapped(Degrees::from(270), 0.5), 180);
+        assert_eq!(Degrees::from(270).lerp_wrapped(c, 0.5), 0);
+
+        // 127 is excluded, so 120 -> -120 is 15 steps, and halfway rounds up
+        let d = WrappingI8::<-128, 127>::from(120);
+        assert_eq!(d.lerp_wrapped((-120).into(), 0.5), -127);
+        assert_eq!(d.lerp_wrapped((-120).into(), 1.0), -120);
+        assert_eq!(d.lerp_wrapped(100.into(), 0.5), 110);
+    }
+
+    // Assigning from a differently bounded value uses its raw inner value,
+    // keeping the bounds of the left-hand side.
+    #[test]
+    #[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+    fn assign_with_other_bounds() {
+        let mut a = WrappingU32::<0, 8>::from(3);
+        a += WrappingU32::<0, 10>::from(9);
+        assert_eq!(a, 4);
+        a -= WrappingU32::<0, 10>::from(9);
+        assert_eq!(a, 3);
+        assert_eq!(a + WrappingU32::<0, 10>::from(9), 12);
+    }
+
+    #[test]
+    fn add_and_sub_rebound() {
+        let a = WrappingU32::<0, 8>::from(3);
+        let b = WrappingU32::<0, 10>::from(9);
+
+        // 9 is rebound to 1 first, so neither of these have to wrap
+        assert_eq!(a.add_rebound(b), 4);
+        assert_eq!(a.sub_rebound(b), 2);
+
+        // 17 is rebound to -3, and -18 to 2
+        let c = WrappingI32::<-5, 5>::from(-1);
+        assert_eq!(c.add_rebound(WrappingI32::<-20, 20>::from(17)), -4);
+        assert_eq!(c.sub_rebound(WrappingI32::<-20, 20>::from(-18)), -3);
+    }
+
+    #[test]
+    fn operators_return_the_raw_result() {
+        let a = WrappingU8::<0, 200>::from(150);
+        assert_eq!(a + 50, 200);
+        assert_eq!(a - 100, 50);
+        assert_eq!(a * 1, 150);
+
+        let b = WrappingI8::<-100, 100>::from(-90);
+        assert_eq!(b - 30, -120);
+        assert_eq!(b * -1, 90);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn operators_overflow_like_the_inner_type() {
+        let _ = WrappingU8::<0, 200>::from(150) + 150;
+    }
 }
@@ -1,7 +1,63 @@
 //! Types that wrap to fit within their given bounds.
+//!
+//! The range is half-open, so `MAX` itself is never reached. A value of
+//! exactly `MAX` wraps around to `MIN`, and a value of `MIN - 1` wraps to
+//! `MAX - 1`:
+//!
+//! ```rust
+//! use clamps::wrapping::{Wrapping, WrappingU8};
+//!
+//! assert_eq!(WrappingU8::<0, 16>::from(16), 0);
+//! assert_eq!(WrappingU8::<0, 16>::from(17), 1);
+//! assert_eq!(WrappingU8::<2, 16>::from(1), 15);
+//! assert_eq!(Wrapping::new(16, 0, 16), 0);
+//! ```
+//!
+//! The concrete types can be converted to and from [`core::num::Wrapping`].
+//! This only makes sense when the bounds match the inner type's own, and as
+//! `MAX` is excluded, the inner type's largest value will wrap around to `MIN`:
+//!
+//! ```rust
+//! use clamps::wrapping::WrappingU8;
+//! use core::num::Wrapping;
+//!
+//! type Full = WrappingU8<0, { u8::MAX }>;
+//! assert_eq!(Full::from(Wrapping(200u8)), 200);
+//! assert_eq!(Wrapping::from(Full::from(200)), Wrapping(200));
+//! assert_eq!(Full::from(Wrapping(u8::MAX)), 0);
+//! ```
+//!
+//! The arithmetic operators of the concrete types return a raw inner value,
+//! which isn't wrapped, so it can be outside of the bounds. They behave exactly
+//! like the inner type's own operators, including overflowing it (which panics
+//! in debug builds). Use the `wrapping_*` methods or the assign operators to
+//! get a wrapped result, which never overflow:
+//!
+//! ```rust
+//! use clamps::wrapping::WrappingU8;
+//!
+//! let a = WrappingU8::<0, 200>::from(150);
+//! assert_eq!(a + 50, 200); // raw, and out of range
+//! # #[cfg(not(all(feature = "strict-debug", debug_assertions)))] {
+//! assert_eq!(a.wrapping_add(50), 0);
+//! assert_eq!(a.wrapping_add(150), 100); // 300 would overflow a u8
+//! # }
+//! ```
 
+#[cfg(feature = "angles")]
+pub mod angles;
+mod character;
+mod constant;
+mod cursor;
+mod float;
 mod generic;
 mod int;
 
+pub use character::*;
+pub use constant::*;
+pub use cursor::*;
+pub use float::*;
 pub use generic::*;
 pub use int::*;
+
+pub(crate) use int::wrap;
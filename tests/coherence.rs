@@ -15,3 +15,247 @@ fn wrapping_t_is_interoperable_with_t() {
     assert_eq!(generic * 2 % 5, 3);
     assert_eq!(concrete / 2, 1);
 }
+
+#[test]
+fn clamped_is_implemented_by_all_families() {
+    use clamps::bounded::{Bounded, BoundedU32};
+    use clamps::reflecting::ReflectingU32;
+    use clamps::saturating::{Saturating, SaturatingU32};
+    use clamps::Clamped;
+
+    fn describe<C: Clamped>(c: &C) -> String
+    where
+        C::Inner: std::fmt::Display,
+    {
+        format!("{} in {}..{}", c.inner(), c.min_bound(), c.max_bound())
+    }
+
+    assert_eq!(describe(&WrappingU32::<2, 8>::from(5)), "5 in 2..8");
+    assert_eq!(describe(&SaturatingU32::<2, 8>::from(5)), "5 in 2..8");
+    assert_eq!(describe(&ReflectingU32::<2, 8>::from(5)), "5 in 2..8");
+    assert_eq!(describe(&BoundedU32::<2, 8>::new(5).unwrap()), "5 in 2..8");
+    assert_eq!(describe(&Wrapping::new(5, 2, 8)), "5 in 2..8");
+    assert_eq!(describe(&Saturating::new(5, 2, 8)), "5 in 2..8");
+    assert_eq!(describe(&Bounded::new(5, 2, 8).unwrap()), "5 in 2..8");
+
+    assert_eq!(Clamped::clamp(&WrappingU32::<2, 8>::from(5), 9), 3);
+    assert_eq!(Clamped::clamp(&SaturatingU32::<2, 8>::from(5), 9), 8);
+    assert_eq!(Saturating::new(5, 2, 8).clamp(9), 8);
+    assert!(Clamped::clamp(&BoundedU32::<2, 8>::new(5).unwrap(), 9).is_err());
+    assert!(Bounded::new(5, 2, 8).unwrap().clamp(7).is_ok());
+}
+
+#[test]
+fn all_families_can_be_constructed_at_compile_time() {
+    use clamps::bounded::{BoundedU32, BoundsError, NonZeroBoundedU32};
+    use clamps::reflecting::ReflectingU32;
+    use clamps::saturating::SaturatingU32;
+
+    const ANGLE: WrappingU32<0, 360> = WrappingU32::new_const(405);
+    static VOLUME: SaturatingU32<0, 11> = SaturatingU32::new_const(20);
+    const BOUNCE: ReflectingU32<0, 10> = ReflectingU32::new_const(13);
+    const BOUNDED: Result<BoundedU32<0, 10>, BoundsError> =
+        BoundedU32::try_new_const(7);
+    const NONZERO: Result<NonZeroBoundedU32<1, 10>, BoundsError> =
+        NonZeroBoundedU32::try_new_const(0);
+
+    const INNER: u32 = ANGLE.inner_const();
+    assert_eq!(INNER, 45);
+    assert_eq!(VOLUME, 11);
+    assert_eq!(BOUNCE, 7);
+    assert_eq!(BOUNDED.unwrap(), 7);
+    assert_eq!(NONZERO, Err(BoundsError::TooSmall));
+}
+
+#[test]
+#[cfg(not(all(feature = "strict-debug", debug_assertions)))]
+fn sum_follows_each_familys_strategy() {
+    use clamps::saturating::{SaturatingI8, SaturatingU8};
+
+    // each addition is clamped in turn, so a plain u8 sum never overflows
+    let values = [150, 150, 150];
+    let wrapped: WrappingU8<0, 4> = values.into_iter().sum();
+    let saturated: SaturatingU8<0, 200> = values.into_iter().sum();
+    assert_eq!(wrapped, 2);
+    assert_eq!(saturated, 200);
+
+    let values = [3, 3, 3];
+    let wrapped: WrappingU8<0, 4> = values.into_iter().sum();
+    let saturated: SaturatingU8<0, 200> = values.into_iter().sum();
+    assert_eq!(wrapped, 1);
+    assert_eq!(saturated, 9);
+
+    // saturating loses whatever was past the bound, while wrapping doesn't
+    let values = [8, 8, -8];
+    let wrapped: WrappingI8<-10, 10> = values.into_iter().sum();
+    let saturated: SaturatingI8<-10, 10> = values.into_iter().sum();
+    assert_eq!(wrapped, 8);
+    assert_eq!(saturated, 2);
+}
+
+#[test]
+#[cfg(feature = "num-traits")]
+fn num_traits_bounded_gives_the_extremes_in_range() {
+    use clamps::bounded::{BoundedInclusiveU32, BoundedU32, NonZeroBoundedU32};
+    use clamps::reflecting::ReflectingU32;
+    use clamps::saturating::SaturatingU32;
+    use num_traits::Bounded;
+
+    fn extremes<T: Bounded + PartialOrd<u32>>() -> (T, T) {
+        (T::min_value(), T::max_value())
+    }
+
+    let (min, max) = extremes::<WrappingU32<2, 8>>();
+    assert!(min == 2 && max == 7);
+    let (min, max) = extremes::<SaturatingU32<2, 8>>();
+    assert!(min == 2 && max == 8);
+    let (min, max) = extremes::<ReflectingU32<2, 8>>();
+    assert!(min == 2 && max == 8);
+    let (min, max) = extremes::<BoundedU32<2, 8>>();
+    assert!(min == 2 && max == 7);
+    let (min, max) = extremes::<BoundedInclusiveU32<2, 8>>();
+    assert!(min == 2 && max == 8);
+    let (min, max) = extremes::<NonZeroBoundedU32<2, 8>>();
+    assert!(min == 2 && max == 7);
+
+    // the extremes can be used with generic num-traits code
+    let lo = SaturatingU32::<2, 8>::min_value();
+    let hi = SaturatingU32::<2, 8>::max_value();
+    let value = SaturatingU32::<2, 8>::from(5);
+    assert_eq!(num_traits::clamp(value, lo, hi), 5);
+}
+
+#[test]
+fn ordering_only_considers_the_inner_value() {
+    use core::cmp::Ordering;
+
+    let a = WrappingU32::<0, 8>::new(5);
+    let b = WrappingU32::<4, 100>::new(5);
+    let c = WrappingU32::<0, 100>::new(50);
+
+    assert_eq!(a, b);
+    assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+    assert!(a < c);
+    assert!(c > b);
+
+    let mut values: Vec<WrappingU32<0, 8>> =
+        [7, 2, 5, 0, 2].into_iter().map(WrappingU32::new).collect();
+    values.sort();
+    assert_eq!(values, [0, 2, 2, 5, 7]);
+
+    // Ord must agree with PartialOrd when the bounds are the same.
+    for x in &values {
+        for y in &values {
+            assert_eq!(Some(x.cmp(y)), x.partial_cmp(y));
+            assert_eq!(x.cmp(y) == Ordering::Equal, x == y);
+        }
+    }
+}
+
+#[test]
+fn all_families_convert_into_their_inner_type() {
+    use clamps::bounded::{
+        BoundedInclusiveU32, BoundedSaturatingU32, BoundedU32,
+        NonZeroBoundedU32,
+    };
+    use clamps::reflecting::ReflectingU32;
+    use clamps::saturating::SaturatingU32;
+
+    fn double(n: impl Into<u32>) -> u32 { n.into() * 2 }
+
+    let n: u32 = WrappingU32::<2, 8>::from(9).into();
+    assert_eq!(n, 3);
+    assert_eq!(u32::from(SaturatingU32::<2, 8>::from(9)), 8);
+    assert_eq!(u32::from(ReflectingU32::<2, 8>::from(9)), 7);
+    assert_eq!(double(BoundedU32::<2, 8>::new(5).unwrap()), 10);
+    assert_eq!(double(BoundedInclusiveU32::<2, 8>::new(8).unwrap()), 16);
+    assert_eq!(double(NonZeroBoundedU32::<2, 8>::new(5).unwrap()), 10);
+    assert_eq!(double(BoundedSaturatingU32::<2, 8>::new(5).unwrap()), 10);
+}
+
+#[test]
+fn clamps_can_be_chained_dynamically() {
+    use clamps::bounded::Bounded;
+    use clamps::saturating::Saturating;
+    use clamps::Clamp;
+
+    let pipeline: Vec<Box<dyn Clamp<u32>>> = vec![
+        Box::new(Saturating::new(0, 0, 100)),
+        Box::new(Wrapping::new(0, 10, 60)),
+        Box::new(Bounded::new(30, 20, 40).unwrap()),
+    ];
+    let apply = |value| pipeline.iter().fold(value, |v, c| c.clamp(v));
+
+    assert_eq!(apply(35), 35);
+    assert_eq!(apply(75), 25);
+
+    // 1000 saturates to 100, and wraps to 50. That's above bounded's range, so
+    // it saturates to 39 (as 40 itself is excluded).
+    assert_eq!(apply(1000), 39);
+    assert_eq!(apply(59), 39);
+
+    // 62 wraps to 12, which is below bounded's range
+    assert_eq!(apply(62), 20);
+}
+
+#[test]
+fn bounds_can_be_read_from_the_type() {
+    use clamps::bounded::{BoundedInclusiveI8, NonZeroBoundedU16};
+    use clamps::reflecting::ReflectingU64;
+    use clamps::saturating::SaturatingI32;
+
+    const WIDTH: u32 = WrappingU32::<2, 8>::MAX_BOUND
+        - WrappingU32::<2, 8>::MIN_BOUND;
+    assert_eq!(WIDTH, 6);
+
+    assert_eq!(SaturatingI32::<-5, 5>::MIN_BOUND, -5);
+    assert_eq!(ReflectingU64::<0, 9>::MAX_BOUND, 9);
+    assert_eq!(BoundedInclusiveI8::<-1, 1>::MAX_BOUND, 1);
+    assert_eq!(NonZeroBoundedU16::<3, 30>::MIN_BOUND, 3);
+    assert_eq!(WrappingChar::<'a', '{'>::MAX_BOUND, '{');
+}
+
+#[test]
+fn map_keys_can_be_looked_up_by_inner_value() {
+    use std::collections::{BTreeMap, HashSet};
+
+    use clamps::bounded::BoundedU32;
+    use clamps::saturating::SaturatingI8;
+
+    let mut map = BTreeMap::new();
+    for key in [5, 50, 99] {
+        map.insert(BoundedU32::<0, 100>::try_from(key).unwrap(), key * 2);
+    }
+    assert_eq!(map.get(&5u32), Some(&10));
+    assert_eq!(map.get(&99u32), Some(&198));
+    assert_eq!(map.get(&6u32), None);
+    assert_eq!(map.range(10u32..60).count(), 1);
+
+    let keys = [-100, 0, 100].map(SaturatingI8::<-5, 5>::from);
+    let set = HashSet::from(keys);
+    assert!(set.contains(&-5i8));
+    assert!(set.contains(&5i8));
+    assert!(!set.contains(&100i8));
+}
+
+#[test]
+fn all_families_convert_to_floats() {
+    use clamps::bounded::{BoundedI128, NonZeroBoundedU8};
+    use clamps::reflecting::ReflectingI16;
+    use clamps::saturating::SaturatingU64;
+
+    assert_eq!(WrappingU32::<0, 360>::from(400).as_f64(), 40.0);
+    assert_eq!(ReflectingI16::<-10, 10>::from(-12).as_f32(), -8.0);
+    assert_eq!(NonZeroBoundedU8::<1, 9>::try_from(3).unwrap().as_f64(), 3.0);
+    assert_eq!(WrappingI8::<-128, 127>::from(-128).as_f32(), -128.0);
+
+    // 2^53 + 1 is the smallest integer an f64 can't represent exactly
+    type Big = SaturatingU64<0, { u64::MAX }>;
+    assert_eq!(Big::from(1 << 53).as_f64(), 9007199254740992.0);
+    assert_eq!(Big::from((1 << 53) + 1).as_f64(), 9007199254740992.0);
+    assert_eq!(Big::from(u64::MAX).as_f64(), 18446744073709551616.0);
+    assert_eq!(Big::from((1 << 24) + 1).as_f32(), 16777216.0);
+
+    let huge = BoundedI128::<{ i128::MIN }, 0>::try_from(i128::MIN).unwrap();
+    assert_eq!(huge.as_f64(), -(2f64.powi(127)));
+}